@@ -0,0 +1,253 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use asset_parser::{
+    content_file::SubmarineAsset,
+    shared::identifier::Identifier,
+    submarine::{
+        diff::diff_submarines,
+        integrity::check_integrity,
+        power::analyze_power,
+        remap::SubmarineRemap,
+        render::{RenderLayers, SvgRenderer},
+        simulation::Simulator,
+        wiring::{Pin, WiringGraph},
+    },
+};
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Export the wiring graph of a submarine file
+    Wiring {
+        sub: PathBuf,
+        #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// Written to stdout if not set
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Run the signal components of a submarine file and print the signals at the traced pins
+    Simulate {
+        sub: PathBuf,
+        #[arg(long, default_value_t = 60)]
+        ticks: u64,
+        /// Signal sent once on the first tick, as `<item id>:<pin>=<value>`
+        #[arg(long)]
+        inject: Vec<String>,
+        /// Signal sent on every tick, as `<item id>:<pin>=<value>`
+        #[arg(long)]
+        hold: Vec<String>,
+        /// Pin to trace, as `<item id>:<pin>`
+        #[arg(long)]
+        trace: Vec<String>,
+    },
+    /// Check the hulls, gaps and waypoints of a submarine file
+    Check { sub: PathBuf },
+    /// Render a submarine file to SVG
+    Render {
+        sub: PathBuf,
+        /// Layers left out of the image
+        #[arg(long, value_enum, value_delimiter = ',')]
+        hide: Vec<Layer>,
+        /// Written to stdout if not set
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Print a table of the power grids of each submarine file
+    Power {
+        #[arg(required = true)]
+        subs: Vec<PathBuf>,
+    },
+    /// Replace item and structure identifiers in a submarine file
+    Remap {
+        sub: PathBuf,
+        /// Identifier replacement, as `<from>=<to>`
+        #[arg(long = "map", required = true)]
+        mappings: Vec<String>,
+        /// Only print the affected entities without writing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrites the submarine file if not set
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Print the entities added, removed or changed between two versions of a submarine file
+    Diff { old: PathBuf, new: PathBuf },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Layer {
+    Hulls,
+    Gaps,
+    Structures,
+    Items,
+    Waypoints,
+    LinkedSubmarines,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Wiring {
+            sub,
+            format,
+            output,
+        } => {
+            let asset = load_sub(&sub)?;
+            let graph = WiringGraph::new(&asset.sub.submarine_info);
+            let s = match format {
+                GraphFormat::Dot => graph.to_dot(),
+                GraphFormat::Json => serde_json::to_string_pretty(&graph)
+                    .map_err(|e| format!("Failed to serialize wiring graph: {}", e))?,
+            };
+            write_output(output.as_deref(), &s)
+        }
+        Command::Simulate {
+            sub,
+            ticks,
+            inject,
+            hold,
+            trace,
+        } => {
+            let asset = load_sub(&sub)?;
+            let mut simulator = Simulator::new(&asset.sub.submarine_info);
+            for v in &inject {
+                let (pin, value) = parse_pin_signal(v)?;
+                simulator.inject(pin, value);
+            }
+            for v in &hold {
+                let (pin, value) = parse_pin_signal(v)?;
+                simulator.hold(pin, value);
+            }
+            for v in &trace {
+                simulator.trace_pin(parse_pin(v)?);
+            }
+            simulator.run(ticks);
+            for event in simulator.trace() {
+                println!(
+                    "{}\t{}:{}\t{}",
+                    event.tick, event.pin.item_id, event.pin.name, event.value
+                );
+            }
+            Ok(())
+        }
+        Command::Check { sub } => {
+            let asset = load_sub(&sub)?;
+            let issues = check_integrity(&asset.sub.submarine_info);
+            for issue in &issues {
+                println!("{}", issue);
+            }
+            if issues.is_empty() {
+                Ok(())
+            } else {
+                Err(format!("{} integrity issues found", issues.len()))
+            }
+        }
+        Command::Render { sub, hide, output } => {
+            let asset = load_sub(&sub)?;
+            let mut layers = RenderLayers::default();
+            for layer in hide {
+                match layer {
+                    Layer::Hulls => layers.hulls = false,
+                    Layer::Gaps => layers.gaps = false,
+                    Layer::Structures => layers.structures = false,
+                    Layer::Items => layers.items = false,
+                    Layer::Waypoints => layers.waypoints = false,
+                    Layer::LinkedSubmarines => layers.linked_submarines = false,
+                }
+            }
+            let svg = SvgRenderer::new(layers).render(&asset.sub.submarine_info);
+            write_output(output.as_deref(), &svg)
+        }
+        Command::Power { subs } => {
+            for sub in &subs {
+                let asset = load_sub(sub)?;
+                let report = analyze_power(&asset.sub.submarine_info);
+                println!("{}\n{}", sub.display(), report.to_table());
+            }
+            Ok(())
+        }
+        Command::Remap {
+            sub,
+            mappings,
+            dry_run,
+            output,
+        } => {
+            let mapping = mappings
+                .iter()
+                .map(|v| {
+                    v.split_once('=')
+                        .map(|(from, to)| (Identifier::from(from), Identifier::from(to)))
+                        .ok_or_else(|| format!("Expected <from>=<to>, got {}", v))
+                })
+                .collect::<Result<HashMap<_, _>, _>>()?;
+            let remap =
+                SubmarineRemap::load_from_path(sub.to_string_lossy().into_owned(), &mapping)
+                    .map_err(|e| format!("Failed to load submarine {}: {}", sub.display(), e))?;
+            for entity in &remap.entities {
+                println!("{}", entity);
+            }
+            if dry_run {
+                return Ok(());
+            }
+            let (_, bytes) = remap
+                .apply()
+                .map_err(|e| format!("Failed to remap submarine {}: {}", sub.display(), e))?;
+            let output = output.unwrap_or(sub);
+            std::fs::write(&output, bytes)
+                .map_err(|e| format!("Failed to write {}: {}", output.display(), e))
+        }
+        Command::Diff { old, new } => {
+            let read = |path: &Path| {
+                std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+            };
+            let diff = diff_submarines(&read(&old)?, &read(&new)?)
+                .map_err(|e| format!("Failed to compare submarines: {}", e))?;
+            print!("{}", diff);
+            Ok(())
+        }
+    }
+}
+
+fn parse_pin(s: &str) -> Result<Pin, String> {
+    let (item_id, name) = s
+        .split_once(':')
+        .ok_or_else(|| format!("Expected <item id>:<pin>, got {}", s))?;
+    Ok(Pin {
+        item_id: item_id
+            .parse()
+            .map_err(|e| format!("Invalid item id {}: {}", item_id, e))?,
+        name: name.to_owned(),
+    })
+}
+
+fn parse_pin_signal(s: &str) -> Result<(Pin, String), String> {
+    let (pin, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Expected <item id>:<pin>=<value>, got {}", s))?;
+    Ok((parse_pin(pin)?, value.to_owned()))
+}
+
+fn load_sub(path: &Path) -> Result<SubmarineAsset, String> {
+    SubmarineAsset::load_from_path(path.to_string_lossy().into_owned())
+        .map_err(|e| format!("Failed to load submarine {}: {}", path.display(), e))
+}
+
+fn write_output(output: Option<&Path>, s: &str) -> Result<(), String> {
+    match output {
+        Some(path) => std::fs::write(path, s)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e)),
+        None => {
+            print!("{}", s);
+            Ok(())
+        }
+    }
+}
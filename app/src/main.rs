@@ -16,16 +16,25 @@ along with this program; if not, write to the Free Software
 Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 */
 
+pub mod cli;
 pub mod log_highlighter;
 pub mod logger;
 pub mod manifest;
 
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use asset_parser::{
+    analysis::{
+        conversations::{conversation_languages, render_conversations_html},
+        disable_impact::disable_impact,
+        talent_trees::render_talent_trees,
+    },
     content_package::{ContentPackage, Regular},
+    game_version::detect_game_version,
     loading::{ConflictType, LoadingState},
-    shared::version::Version,
 };
 use clap::Parser;
 use iced::{
@@ -43,19 +52,31 @@ use logger::SimpleLogger;
 use manifest::{ConflictStoreData, ModIdentifier, ModManifest};
 use strum::IntoEnumIterator;
 
-const CURRENT_GAME_VERSION: Version = Version {
-    major: 1,
-    minor: 7,
-    build: Some(7),
-    revision: Some(0),
-};
-
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Args {
     #[arg(default_value = r#"C:\Program Files (x86)\Steam\steamapps\common\Barotrauma"#)]
     game_path: String,
     config_player_path: Option<String>,
     patch_mod_path: Option<String>,
+    /// Standalone .sub files to check the required content packages of
+    #[arg(long = "sub")]
+    sub_files: Vec<String>,
+    /// Package id (name or workshop id) to log the impact of disabling for once the mods are parsed
+    #[arg(long)]
+    disable_impact: Option<String>,
+    /// File to export the store price report to as CSV once the mods are parsed
+    #[arg(long)]
+    store_prices_csv: Option<PathBuf>,
+    /// File to export the effective talent tree of every job to once the mods are parsed
+    #[arg(long)]
+    talent_trees: Option<PathBuf>,
+    /// Directory to export the NPC conversation trees of every language to as HTML once the mods are parsed
+    #[arg(long)]
+    conversations_html: Option<PathBuf>,
+    /// Run a command without opening the GUI
+    #[command(subcommand)]
+    command: Option<cli::Command>,
 }
 
 impl Args {
@@ -256,6 +277,19 @@ impl App {
 
                     row![
                         container(column![
+                            text!(
+                                "Packages with code: {}",
+                                if conflicts.packages_with_code.is_empty() {
+                                    "None".to_owned()
+                                } else {
+                                    conflicts
+                                        .packages_with_code
+                                        .iter()
+                                        .map(|v| format!("{} ({})", v.package_id(), v.code()))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                }
+                            ),
                             pick_list(
                                 ConflictType::iter()
                                     .filter(|t| !t.get_conflict_by_type(conflicts).is_empty())
@@ -295,7 +329,11 @@ impl App {
                                         conflict_data.added_by.iter().enumerate().map(
                                             |(i, package)| {
                                                 Into::<Element<'_, Message>>::into(
-                                                    button(text!("{}", package.package_id()))
+                                                    button(if package.code().has_code() {
+                                                        text!("{} (has code: {})", package.package_id(), package.code())
+                                                    } else {
+                                                        text!("{}", package.package_id())
+                                                    })
                                                         .on_press_maybe(
                                                             if self
                                                                 .selected_conflict_file_index
@@ -378,6 +416,7 @@ impl App {
             Message::StartParsing => {
                 let game_path = PathBuf::from(&self.args.game_path);
                 let config_player_path = self.args.config_player_path();
+                let sub_files = self.args.sub_files.iter().map(PathBuf::from).collect();
 
                 self.selected_conflict_file_index = None;
                 self.selected_conflict_index = None;
@@ -385,7 +424,11 @@ impl App {
 
                 self.loading_state = Some(LoadingState::Started);
 
-                let task = Task::stream(asset_parser::loading::load(game_path, config_player_path));
+                let task = Task::stream(asset_parser::loading::load(
+                    game_path,
+                    config_player_path,
+                    sub_files,
+                ));
 
                 return Task::done(Message::ScreenChanged(Screen::LoadingMods))
                     .chain(task.map(|progress| Message::LoadProgress(progress)))
@@ -502,6 +545,55 @@ impl App {
             }
             Message::PatchModLoaded(patch_mod_package) => {
                 self.patch_mod = Some(Arc::into_inner(patch_mod_package).expect("There's supposed to be only 1 reference to patch mod at the point of transfering to the app state"));
+                if let Some(package_id) = &self.args.disable_impact
+                    && let Some(LoadingState::Finished(loaded_content_files, conflicts)) =
+                        &self.loading_state
+                {
+                    match disable_impact(
+                        loaded_content_files,
+                        conflicts,
+                        package_id,
+                        self.patch_mod.as_ref().map(|(v, _)| &v.file_paths),
+                    ) {
+                        Some(impact) => log::info!("{}", impact),
+                        None => log::error!("Package {} is not enabled", package_id),
+                    }
+                }
+                if let Some(path) = &self.args.store_prices_csv
+                    && let Some(LoadingState::Finished(_, conflicts)) = &self.loading_state
+                {
+                    match std::fs::write(path, conflicts.store_prices.to_csv()) {
+                        Ok(()) => log::info!("Exported store prices to {}", path.display()),
+                        Err(e) => log::error!("Failed to export store prices: {}", e),
+                    }
+                }
+                if let Some(path) = &self.args.talent_trees
+                    && let Some(LoadingState::Finished(loaded_content_files, _)) =
+                        &self.loading_state
+                {
+                    match std::fs::write(path, render_talent_trees(loaded_content_files)) {
+                        Ok(()) => log::info!("Exported talent trees to {}", path.display()),
+                        Err(e) => log::error!("Failed to export talent trees: {}", e),
+                    }
+                }
+                if let Some(directory) = &self.args.conversations_html
+                    && let Some(LoadingState::Finished(loaded_content_files, _)) =
+                        &self.loading_state
+                {
+                    if let Err(e) = std::fs::create_dir_all(directory) {
+                        log::error!("Failed to export conversations: {}", e);
+                    }
+                    for language in conversation_languages(loaded_content_files) {
+                        let path = directory.join(format!("{}.html", language));
+                        match std::fs::write(
+                            &path,
+                            render_conversations_html(loaded_content_files, language),
+                        ) {
+                            Ok(()) => log::info!("Exported conversations to {}", path.display()),
+                            Err(e) => log::error!("Failed to export conversations: {}", e),
+                        }
+                    }
+                }
             }
             Message::PatchModPathChanged(s) => {
                 self.args.patch_mod_path = Some(s);
@@ -529,7 +621,12 @@ impl App {
                     );
                     let mut package = ContentPackage::<Regular>::default();
                     package.name = Some("Conflict Finder Patch Mod".to_owned());
-                    package.game_version = Some(CURRENT_GAME_VERSION);
+                    package.game_version = detect_game_version(Path::new(&self.args.game_path));
+                    if package.game_version.is_none() {
+                        log::warn!(
+                            "Failed to detect the game version, Patch Mod will be created without one."
+                        );
+                    }
 
                     let manifest = ModManifest {
                         dependencies: loaded_content_files.iter().map(|f| Arc::new(std::sync::Mutex::new(ModIdentifier {
@@ -628,7 +725,7 @@ impl App {
                 }
 
                 manifest.in_progress_conflicts.entry(self.selected_conflict_type).or_default().insert(ConflictStoreData {
-                    identifier: conflict.0.clone(),
+                    identifier: conflict.0.to_string(),
                     conflict_between: conflict.1.added_by.iter().map(|v| {
                         let v = v.package_id_prefer_ugc_id();
                         manifest.dependencies.iter().find(|d| d.lock().unwrap().identifier == v).expect("Invalid mod manifest: mod used in a conflict was not found in this mod's dependencies").clone()
@@ -666,6 +763,14 @@ fn log_worker(rx: Arc<Mutex<UnboundedReceiver<String>>>) -> impl Stream<Item = M
 }
 
 fn main() -> iced::Result {
+    if let Some(command) = Args::parse().command {
+        if let Err(e) = cli::run(command) {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     let logger = SimpleLogger::new()
         .with_module_level("wgpu_core", LevelFilter::Warn)
         .with_module_level("naga", LevelFilter::Warn)
@@ -0,0 +1,128 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use crate::{
+    content_file::{SubmarineAsset, SubmarineAssetType},
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{
+        identifier::Identifier,
+        prefabs::{item_prefab::ItemPrefab, location_type::LocationType},
+    },
+};
+
+pub mod conversations;
+pub mod crafting_exploits;
+pub mod dangling_references;
+pub mod disable_impact;
+pub mod events;
+pub mod localization;
+pub mod missing_prefabs;
+pub mod obtainability;
+pub mod required_packages;
+pub mod store_prices;
+pub mod submarine_conflicts;
+pub mod talent_trees;
+pub mod variants;
+
+#[derive(Debug, Clone)]
+pub struct SubmarineRef {
+    pub package: Arc<AnyContentPackage>,
+    pub asset_type: SubmarineAssetType,
+    pub file_path: String,
+    pub hash: [u8; 16],
+}
+
+impl SubmarineRef {
+    pub fn new(
+        package: &Arc<AnyContentPackage>,
+        asset_type: SubmarineAssetType,
+        asset: &SubmarineAsset,
+    ) -> Self {
+        Self {
+            package: package.clone(),
+            asset_type,
+            file_path: asset.file_path.clone(),
+            hash: asset.hash,
+        }
+    }
+}
+
+/// Which package provides each item and structure identifier or alias, the last package in load order wins like in game
+#[derive(Debug, Default)]
+pub struct PrefabProviders {
+    pub items: HashMap<Identifier, Arc<AnyContentPackage>>,
+    pub structures: HashMap<Identifier, Arc<AnyContentPackage>>,
+}
+
+impl PrefabProviders {
+    pub fn new<'a>(
+        packages: impl IntoIterator<Item = &'a (Arc<AnyContentPackage>, ContentFiles)>,
+    ) -> Self {
+        let packages = packages.into_iter().collect::<Vec<_>>();
+        let mut providers = Self::default();
+        for (package, files) in &packages {
+            for item in files.items.iter().flat_map(|file| &file.items) {
+                providers
+                    .items
+                    .insert(item.value.get_identifier().clone(), package.clone());
+            }
+            for structure in files.structures.iter().flat_map(|file| &file.prefabs) {
+                providers
+                    .structures
+                    .insert(structure.value.get_identifier().clone(), package.clone());
+            }
+        }
+        //submarines find prefabs by alias when no prefab has the identifier, e.g. after an item got renamed
+        for (package, files) in packages.iter().rev() {
+            for item in files.items.iter().flat_map(|file| &file.items) {
+                for alias in item.value.aliases.iter().flatten() {
+                    providers
+                        .items
+                        .entry(alias.clone())
+                        .or_insert_with(|| package.clone());
+                }
+            }
+            for structure in files.structures.iter().flat_map(|file| &file.prefabs) {
+                for alias in &structure.value.aliases {
+                    providers
+                        .structures
+                        .entry(alias.clone())
+                        .or_insert_with(|| package.clone());
+                }
+            }
+        }
+        providers
+    }
+}
+
+/// Item prefabs that end up loaded with the package defining them, the last package in load order wins like in game
+pub fn effective_items(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> BTreeMap<&Identifier, (&Arc<AnyContentPackage>, &ItemPrefab)> {
+    let mut items = BTreeMap::new();
+    for (package, files) in packages {
+        for item in files.items.iter().flat_map(|file| &file.items) {
+            items.insert(item.value.get_identifier(), (package, &item.value));
+        }
+    }
+    items
+}
+
+/// Location types that end up loaded, the last package in load order wins like in game
+pub fn effective_location_types(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> BTreeMap<&Identifier, &LocationType> {
+    let mut location_types = BTreeMap::new();
+    for (_, files) in packages {
+        for location_type in files
+            .location_types
+            .iter()
+            .flat_map(|file| &file.location_types)
+        {
+            location_types.insert(&location_type.value.identifier, &location_type.value);
+        }
+    }
+    location_types
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::{Display, Write},
+    sync::Arc,
+};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{
+        content_files::npc_conversations_file::NPCConversation, identifier::Identifier,
+        util::escape_xml,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub enum ConversationIssueKind {
+    UnknownJob(Identifier),
+    /// Speakers are assigned in order, so a line can only use a speaker who already talked or the next new one
+    SpeakerOutOfRange {
+        speaker: u32,
+        participants: u32,
+    },
+    EmptyLine,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConversationIssue {
+    pub package: Arc<AnyContentPackage>,
+    pub file_path: String,
+    pub line: String,
+    pub kind: ConversationIssueKind,
+}
+
+impl Display for ConversationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Conversation line \"{}\" in {} of {}: ",
+            self.line,
+            self.file_path,
+            self.package.package_id()
+        )?;
+        match &self.kind {
+            ConversationIssueKind::UnknownJob(job) => write!(f, "allows undefined job {}", job),
+            ConversationIssueKind::SpeakerOutOfRange {
+                speaker,
+                participants,
+            } => write!(
+                f,
+                "speaker {} is beyond the {} participants so far",
+                speaker, participants
+            ),
+            ConversationIssueKind::EmptyLine => write!(f, "line is empty"),
+        }
+    }
+}
+
+/// A line shipped by more than one package in the same language
+#[derive(Debug, Clone)]
+pub struct DuplicateLine {
+    pub language: String,
+    pub line: String,
+    pub packages: Vec<Arc<AnyContentPackage>>,
+}
+
+#[derive(Debug, Default)]
+pub struct ConversationReport {
+    pub issues: Vec<ConversationIssue>,
+    pub duplicates: Vec<DuplicateLine>,
+}
+
+fn check_conversation(
+    conversation: &NPCConversation,
+    participants: u32,
+    jobs: &HashSet<Identifier>,
+    push: &mut impl FnMut(&NPCConversation, ConversationIssueKind),
+) {
+    if conversation.line.trim().is_empty() {
+        push(conversation, ConversationIssueKind::EmptyLine);
+    }
+    for job in &conversation.allowed_jobs {
+        if !jobs.contains(job) {
+            push(conversation, ConversationIssueKind::UnknownJob(job.clone()));
+        }
+    }
+    if conversation.speaker_index > participants {
+        push(
+            conversation,
+            ConversationIssueKind::SpeakerOutOfRange {
+                speaker: conversation.speaker_index,
+                participants,
+            },
+        );
+    }
+    let participants = participants.max(conversation.speaker_index + 1);
+    for response in &conversation.responses {
+        check_conversation(response, participants, jobs, push);
+    }
+}
+
+fn collect_lines<'a>(conversation: &'a NPCConversation, lines: &mut Vec<&'a str>) {
+    lines.push(&conversation.line);
+    for response in &conversation.responses {
+        collect_lines(response, lines);
+    }
+}
+
+pub fn check_conversations(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> ConversationReport {
+    let jobs = packages
+        .iter()
+        .flat_map(|(_, files)| &files.jobs)
+        .flat_map(|file| &file.jobs)
+        .map(|v| v.value.identifier.clone())
+        .collect::<HashSet<_>>();
+
+    let mut report = ConversationReport::default();
+    //conversation files are additive, so the same line in several packages is said twice as often
+    let mut lines: BTreeMap<(&str, String), Vec<&Arc<AnyContentPackage>>> = BTreeMap::new();
+    for (package, files) in packages {
+        for file in &files.npc_conversations {
+            let mut push = |conversation: &NPCConversation, kind| {
+                report.issues.push(ConversationIssue {
+                    package: package.clone(),
+                    file_path: file.file_path.clone(),
+                    line: conversation.line.clone(),
+                    kind,
+                })
+            };
+            let mut file_lines = Vec::new();
+            for conversation in &file.conversations {
+                check_conversation(conversation, 0, &jobs, &mut push);
+                collect_lines(conversation, &mut file_lines);
+            }
+            for line in file_lines.into_iter().filter(|v| !v.trim().is_empty()) {
+                let defined_by = lines
+                    .entry((file.language.as_str(), line.trim().to_lowercase()))
+                    .or_default();
+                if !defined_by.iter().any(|v| Arc::ptr_eq(v, package)) {
+                    defined_by.push(package);
+                }
+            }
+        }
+    }
+    report.duplicates = lines
+        .into_iter()
+        .filter(|(_, packages)| packages.len() > 1)
+        .map(|((language, line), packages)| DuplicateLine {
+            language: language.to_owned(),
+            line,
+            packages: packages.into_iter().cloned().collect(),
+        })
+        .collect();
+    report
+}
+
+/// Languages any enabled package has conversations in, ignoring case like [`render_conversations_html`] does
+pub fn conversation_languages(packages: &[(Arc<AnyContentPackage>, ContentFiles)]) -> Vec<&str> {
+    let mut languages = BTreeMap::new();
+    for file in packages
+        .iter()
+        .flat_map(|(_, files)| &files.npc_conversations)
+    {
+        languages
+            .entry(file.language.to_lowercase())
+            .or_insert(file.language.as_str());
+    }
+    languages.into_values().collect()
+}
+
+fn render_conversation(s: &mut String, conversation: &NPCConversation) {
+    let mut details = Vec::new();
+    if !conversation.allowed_jobs.is_empty() {
+        details.push(format!(
+            "jobs: {}",
+            conversation
+                .allowed_jobs
+                .iter()
+                .map(Identifier::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if !conversation.flags.is_empty() {
+        details.push(format!("flags: {}", conversation.flags.join(", ")));
+    }
+    let summary = format!(
+        "<b>{}</b> {} <small>{}</small>",
+        conversation.speaker_index,
+        escape_xml(&conversation.line),
+        escape_xml(&details.join("; "))
+    );
+    if conversation.responses.is_empty() {
+        let _ = writeln!(s, "<div class=\"line\">{}</div>", summary);
+        return;
+    }
+    let _ = writeln!(s, "<details><summary>{}</summary>", summary);
+    for response in &conversation.responses {
+        render_conversation(s, response);
+    }
+    s.push_str("</details>\n");
+}
+
+/// Conversation trees of a language as collapsible HTML, grouped by package and file
+pub fn render_conversations_html(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    language: &str,
+) -> String {
+    let mut s = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>NPC conversations ({0})</title>\n<style>details, .line {{ margin-left: 1.5em; }} small {{ color: gray; }}</style>\n</head>\n<body>\n<h1>NPC conversations ({0})</h1>\n",
+        escape_xml(language)
+    );
+    for (package, files) in packages {
+        let files = files
+            .npc_conversations
+            .iter()
+            .filter(|file| file.language.eq_ignore_ascii_case(language))
+            .collect::<Vec<_>>();
+        if files.is_empty() {
+            continue;
+        }
+        let _ = writeln!(s, "<h2>{}</h2>", escape_xml(&package.package_id()));
+        for file in files {
+            let _ = writeln!(s, "<h3>{}</h3>", escape_xml(&file.file_path));
+            for conversation in &file.conversations {
+                render_conversation(&mut s, conversation);
+            }
+        }
+    }
+    s.push_str("</body>\n</html>\n");
+    s
+}
@@ -0,0 +1,423 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    sync::Arc,
+};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{
+        identifier::Identifier,
+        prefabs::{
+            item_prefab::{ItemPrefab, RequiredItem},
+            location_type::LocationType,
+        },
+    },
+};
+
+use super::{effective_location_types, variants::ResolvedItems};
+
+/// Longest conversion cycle searched for, longer loops are impractical to exploit anyway
+const MAX_CYCLE_LENGTH: usize = 4;
+/// Ignores floating point noise in the gain of a cycle
+const GAIN_EPSILON: f32 = 1e-3;
+/// Share of the price stores pay for sold items when no location type sets it
+const DEFAULT_SELL_PRICE_MODIFIER: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conversion {
+    Fabricate,
+    Deconstruct,
+}
+
+impl Display for Conversion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Fabricate => "fabricate",
+                Self::Deconstruct => "deconstruct",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConversionStep {
+    pub conversion: Conversion,
+    pub from: Identifier,
+    pub to: Identifier,
+    /// Amount of `to` gained per `from` spent, the other inputs are listed separately
+    pub ratio: f32,
+    /// Package whose item definition adds this step, the product for fabrication and the source for deconstruction
+    pub package: Arc<AnyContentPackage>,
+    /// Other materials the fabrication recipe needs
+    pub other_inputs: Vec<Identifier>,
+}
+
+/// Fabrication and deconstruction loop ending with more of an item than it started with
+#[derive(Debug, Clone)]
+pub struct MaterialCycle {
+    pub steps: Vec<ConversionStep>,
+    /// Amount of the first item gained per one spent
+    pub gain: f32,
+}
+
+impl MaterialCycle {
+    pub fn packages(&self) -> Vec<Arc<AnyContentPackage>> {
+        unique_packages(self.steps.iter().map(|v| &v.package))
+    }
+}
+
+impl Display for MaterialCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            if i == 0 {
+                write!(f, "{}", step.from)?;
+            }
+            write!(f, " -{} x{}-> {}", step.conversion, step.ratio, step.to)?;
+            if !step.other_inputs.is_empty() {
+                write!(f, " (also needs {:?})", step.other_inputs)?;
+            }
+        }
+        write!(
+            f,
+            " gains x{} with definitions from {:?}",
+            self.gain,
+            self.packages()
+                .iter()
+                .map(|v| v.package_id())
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
+/// Buying materials in a store, converting them and selling the result back to the same store for a profit
+///
+/// Buying costs the price times the buying price multiplier, selling gets the price times the sell price modifier of the location type.
+/// Stores aren't tied to a location type, so the one paying the most for sold items is assumed
+#[derive(Debug, Clone)]
+pub struct Arbitrage {
+    pub store: Identifier,
+    /// Location type whose sell price modifier is used, `None` when no location type has an outpost
+    pub location_type: Option<Identifier>,
+    pub conversion: Conversion,
+    /// Fabricated or deconstructed item
+    pub item: Identifier,
+    pub cost: f32,
+    pub revenue: f32,
+    pub packages: Vec<Arc<AnyContentPackage>>,
+}
+
+impl Display for Arbitrage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Store {}{}: {} {} costs {} and sells for {}, prices from {:?}",
+            self.store,
+            self.location_type
+                .as_ref()
+                .map(|v| format!(" in {}", v))
+                .unwrap_or_default(),
+            self.conversion,
+            self.item,
+            self.cost,
+            self.revenue,
+            self.packages
+                .iter()
+                .map(|v| v.package_id())
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CraftingExploits {
+    pub cycles: Vec<MaterialCycle>,
+    pub arbitrage: Vec<Arbitrage>,
+}
+
+fn unique_packages<'a>(
+    packages: impl Iterator<Item = &'a Arc<AnyContentPackage>>,
+) -> Vec<Arc<AnyContentPackage>> {
+    let mut unique: Vec<Arc<AnyContentPackage>> = Vec::new();
+    for package in packages {
+        if !unique.iter().any(|v| Arc::ptr_eq(v, package)) {
+            unique.push(package.clone());
+        }
+    }
+    unique
+}
+
+struct StorePrice {
+    price: u32,
+    can_be_bought: bool,
+    buying_price_multiplier: f32,
+}
+
+/// Stores without their own price info for an item use its default one like in game
+fn store_price(item: &ItemPrefab, store: &Identifier) -> Option<StorePrice> {
+    match item.store_prices.get(store) {
+        Some(v) => Some(StorePrice {
+            price: v.price,
+            can_be_bought: v.can_be_bought,
+            buying_price_multiplier: v.buying_price_multiplier,
+        }),
+        None => item.default_price.as_ref().map(|v| StorePrice {
+            price: v.price,
+            can_be_bought: v.can_be_bought,
+            buying_price_multiplier: v.buying_price_multiplier,
+        }),
+    }
+}
+
+fn buy_cost(item: &ItemPrefab, store: &Identifier) -> Option<f32> {
+    store_price(item, store)
+        .filter(|v| v.can_be_bought && v.price > 0)
+        .map(|v| v.price as f32 * v.buying_price_multiplier)
+}
+
+fn sell_revenue(item: &ItemPrefab, store: &Identifier, sell_price_modifier: f32) -> Option<f32> {
+    store_price(item, store)
+        .filter(|v| v.price > 0)
+        .map(|v| v.price as f32 * sell_price_modifier)
+}
+
+struct ItemSet<'a> {
+    items: std::collections::BTreeMap<&'a Identifier, (&'a Arc<AnyContentPackage>, &'a ItemPrefab)>,
+    tagged: HashMap<&'a Identifier, Vec<&'a Identifier>>,
+}
+
+impl<'a> ItemSet<'a> {
+    fn new(resolved: &'a ResolvedItems) -> Self {
+        let items = resolved.items();
+        let mut tagged: HashMap<&Identifier, Vec<&Identifier>> = HashMap::new();
+        for (identifier, (_, item)) in &items {
+            for tag in item.tags.iter().flatten() {
+                tagged.entry(tag).or_default().push(identifier);
+            }
+        }
+        Self { items, tagged }
+    }
+
+    /// Items that can be used for a recipe requirement
+    fn candidates(&self, required: &'a RequiredItem) -> Vec<&'a Identifier> {
+        match required {
+            RequiredItem::ByIdentifier { identifier, .. } => self
+                .items
+                .get_key_value(identifier)
+                .map(|(v, _)| vec![*v])
+                .unwrap_or_default(),
+            RequiredItem::ByTag { tag, .. } => self.tagged.get(tag).cloned().unwrap_or_default(),
+        }
+    }
+
+    fn conversion_steps(&self) -> Vec<ConversionStep> {
+        let mut steps = Vec::new();
+        for (identifier, (package, item)) in &self.items {
+            for recipe in &item.fabrication_recipes {
+                for (i, required) in recipe.required_items.iter().enumerate() {
+                    let other_inputs = recipe
+                        .required_items
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, _)| *j != i)
+                        .map(|(_, v)| v.target().clone())
+                        .collect::<Vec<_>>();
+                    for from in self.candidates(required) {
+                        steps.push(ConversionStep {
+                            conversion: Conversion::Fabricate,
+                            from: from.clone(),
+                            to: (*identifier).clone(),
+                            ratio: recipe.amount as f32 / required.amount().max(1) as f32,
+                            package: (*package).clone(),
+                            other_inputs: other_inputs.clone(),
+                        });
+                    }
+                }
+            }
+            //random outputs can't be relied on to multiply materials
+            if item.random_deconstruction_output {
+                continue;
+            }
+            for output in &item.deconstruct_items {
+                steps.push(ConversionStep {
+                    conversion: Conversion::Deconstruct,
+                    from: (*identifier).clone(),
+                    to: output.item_identifier.clone(),
+                    ratio: output.amount as f32,
+                    package: (*package).clone(),
+                    other_inputs: Vec::new(),
+                });
+            }
+        }
+        steps
+    }
+
+    fn find_cycles(&self) -> Vec<MaterialCycle> {
+        let index = self
+            .items
+            .keys()
+            .enumerate()
+            .map(|(i, v)| (*v, i))
+            .collect::<HashMap<_, _>>();
+        let steps = self
+            .conversion_steps()
+            .into_iter()
+            .filter_map(|v| Some((index[&v.from], *index.get(&v.to)?, v)))
+            .collect::<Vec<_>>();
+        let mut adjacency = vec![Vec::new(); index.len()];
+        for (i, (from, _, _)) in steps.iter().enumerate() {
+            adjacency[*from].push(i);
+        }
+
+        struct Search<'s> {
+            steps: &'s [(usize, usize, ConversionStep)],
+            adjacency: &'s [Vec<usize>],
+            path: Vec<usize>,
+            cycles: Vec<MaterialCycle>,
+        }
+
+        impl Search<'_> {
+            //cycles are only searched from their lowest node so each is found once
+            fn visit(&mut self, start: usize, node: usize) {
+                for &step in &self.adjacency[node] {
+                    let (_, to, _) = &self.steps[step];
+                    if *to == start {
+                        self.path.push(step);
+                        let gain = self
+                            .path
+                            .iter()
+                            .map(|v| self.steps[*v].2.ratio)
+                            .product::<f32>();
+                        if gain > 1.0 + GAIN_EPSILON {
+                            self.cycles.push(MaterialCycle {
+                                steps: self.path.iter().map(|v| self.steps[*v].2.clone()).collect(),
+                                gain,
+                            });
+                        }
+                        self.path.pop();
+                    } else if *to > start
+                        && self.path.len() + 1 < MAX_CYCLE_LENGTH
+                        && !self.path.iter().any(|v| self.steps[*v].0 == *to)
+                    {
+                        self.path.push(step);
+                        self.visit(start, *to);
+                        self.path.pop();
+                    }
+                }
+            }
+        }
+
+        let mut search = Search {
+            steps: &steps,
+            adjacency: &adjacency,
+            path: Vec::new(),
+            cycles: Vec::new(),
+        };
+        for start in 0..index.len() {
+            search.visit(start, start);
+        }
+        search.cycles
+    }
+
+    fn find_arbitrage(&self, location_type: Option<&LocationType>) -> Vec<Arbitrage> {
+        let sell_price_modifier =
+            location_type.map_or(DEFAULT_SELL_PRICE_MODIFIER, |v| v.store_sell_price_modifier);
+        let stores = self
+            .items
+            .values()
+            .flat_map(|(_, item)| item.store_prices.keys())
+            .collect::<BTreeSet<_>>();
+        let mut result = Vec::new();
+        for store in stores {
+            for (identifier, (package, item)) in &self.items {
+                if let Some(revenue) = sell_revenue(item, store, sell_price_modifier) {
+                    for recipe in &item.fabrication_recipes {
+                        let mut cost = recipe.required_money as f32;
+                        let mut packages = vec![*package];
+                        let mut buyable = true;
+                        for required in &recipe.required_items {
+                            let cheapest = self
+                                .candidates(required)
+                                .into_iter()
+                                .filter_map(|v| {
+                                    let (package, item) = self.items[v];
+                                    Some((buy_cost(item, store)?, package))
+                                })
+                                .min_by(|a, b| a.0.total_cmp(&b.0));
+                            let Some((price, input_package)) = cheapest else {
+                                buyable = false;
+                                break;
+                            };
+                            cost += price * required.amount() as f32;
+                            packages.push(input_package);
+                        }
+                        let revenue = revenue * recipe.amount as f32;
+                        if buyable && revenue > cost {
+                            result.push(Arbitrage {
+                                store: store.clone(),
+                                location_type: location_type.map(|v| v.identifier.clone()),
+                                conversion: Conversion::Fabricate,
+                                item: (*identifier).clone(),
+                                cost,
+                                revenue,
+                                packages: unique_packages(packages.into_iter()),
+                            });
+                        }
+                    }
+                }
+
+                if item.random_deconstruction_output || item.deconstruct_items.is_empty() {
+                    continue;
+                }
+                let Some(cost) = buy_cost(item, store) else {
+                    continue;
+                };
+                let mut revenue = 0.0;
+                let mut packages = vec![*package];
+                for output in &item.deconstruct_items {
+                    if let Some((output_package, output_item)) =
+                        self.items.get(&output.item_identifier)
+                        && let Some(price) = sell_revenue(output_item, store, sell_price_modifier)
+                    {
+                        revenue += price * output.amount as f32;
+                        packages.push(output_package);
+                    }
+                }
+                if revenue > cost {
+                    result.push(Arbitrage {
+                        store: store.clone(),
+                        location_type: location_type.map(|v| v.identifier.clone()),
+                        conversion: Conversion::Deconstruct,
+                        item: (*identifier).clone(),
+                        cost,
+                        revenue,
+                        packages: unique_packages(packages.into_iter()),
+                    });
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Finds material multiplying fabrication/deconstruction loops and per store arbitrage over the effective item set
+pub fn find_crafting_exploits(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    resolved: &ResolvedItems,
+) -> CraftingExploits {
+    let items = ItemSet::new(resolved);
+    let location_type = effective_location_types(packages)
+        .into_values()
+        .filter(|v| v.has_outpost)
+        .max_by(|a, b| {
+            a.store_sell_price_modifier
+                .total_cmp(&b.store_sell_price_modifier)
+        });
+    CraftingExploits {
+        cycles: items.find_cycles(),
+        arbitrage: items.find_arbitrage(location_type),
+    }
+}
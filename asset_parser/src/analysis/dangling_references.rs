@@ -0,0 +1,263 @@
+use std::{collections::HashSet, fmt::Display, sync::Arc};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{identifier::Identifier, prefabs::item_prefab::RequiredItem},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceKind {
+    Item,
+    ItemTag,
+    Job,
+    Order,
+    Mission,
+    LocationType,
+}
+
+impl Display for ReferenceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Item => "item",
+                Self::ItemTag => "item tag",
+                Self::Job => "job",
+                Self::Order => "order",
+                Self::Mission => "mission",
+                Self::LocationType => "location type",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DanglingReference {
+    pub kind: ReferenceKind,
+    pub identifier: Identifier,
+    /// Prefab holding the reference, e.g. `recipe of item fabricator`
+    pub referrer: String,
+}
+
+impl Display for DanglingReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} references undefined {} {}",
+            self.referrer, self.kind, self.identifier
+        )
+    }
+}
+
+/// Dangling references of a single package
+#[derive(Debug)]
+pub struct PackageReferences {
+    pub package: Arc<AnyContentPackage>,
+    pub references: Vec<DanglingReference>,
+}
+
+/// Identifiers defined by any enabled package
+#[derive(Debug, Default)]
+pub struct DefinedIdentifiers {
+    pub items: HashSet<Identifier>,
+    pub item_tags: HashSet<Identifier>,
+    pub jobs: HashSet<Identifier>,
+    pub orders: HashSet<Identifier>,
+    pub missions: HashSet<Identifier>,
+    pub location_types: HashSet<Identifier>,
+}
+
+impl DefinedIdentifiers {
+    pub fn new<'a>(files: impl IntoIterator<Item = &'a ContentFiles>) -> Self {
+        let mut defined = Self::default();
+        for files in files {
+            for item in files.items.iter().flat_map(|file| &file.items) {
+                defined.items.insert(item.value.get_identifier().clone());
+                defined
+                    .item_tags
+                    .extend(item.value.tags.iter().flatten().cloned());
+            }
+            for job in files.jobs.iter().flat_map(|file| &file.jobs) {
+                defined.jobs.insert(job.value.identifier.clone());
+            }
+            for order in files
+                .order_prefabs
+                .iter()
+                .flat_map(|file| &file.order_prefabs)
+            {
+                defined.orders.insert(order.value.identifier.clone());
+            }
+            for mission in files
+                .mission_prefabs
+                .iter()
+                .flat_map(|file| &file.mission_prefabs)
+            {
+                defined.missions.insert(mission.value.identifier.clone());
+            }
+            for location_type in files
+                .location_types
+                .iter()
+                .flat_map(|file| &file.location_types)
+            {
+                defined
+                    .location_types
+                    .insert(location_type.value.identifier.clone());
+            }
+        }
+        defined
+    }
+
+    pub fn contains(&self, kind: ReferenceKind, identifier: &Identifier) -> bool {
+        match kind {
+            ReferenceKind::Item => self.items.contains(identifier),
+            ReferenceKind::ItemTag => self.item_tags.contains(identifier),
+            ReferenceKind::Job => self.jobs.contains(identifier),
+            ReferenceKind::Order => self.orders.contains(identifier),
+            ReferenceKind::Mission => self.missions.contains(identifier),
+            ReferenceKind::LocationType => self.location_types.contains(identifier),
+        }
+    }
+}
+
+/// Every identifier reference made by the files of a package
+pub fn references(files: &ContentFiles) -> Vec<DanglingReference> {
+    let mut references = Vec::new();
+    let mut push = |kind, identifier: &Identifier, referrer: String| {
+        references.push(DanglingReference {
+            kind,
+            identifier: identifier.clone(),
+            referrer,
+        })
+    };
+
+    for item in files.items.iter().flat_map(|file| &file.items) {
+        let item = &item.value;
+        for recipe in &item.fabrication_recipes {
+            for required in &recipe.required_items {
+                let (kind, identifier) = match required {
+                    RequiredItem::ByIdentifier { identifier, .. } => {
+                        (ReferenceKind::Item, identifier)
+                    }
+                    RequiredItem::ByTag { tag, .. } => (ReferenceKind::ItemTag, tag),
+                };
+                push(
+                    kind,
+                    identifier,
+                    format!("recipe of item {}", item.get_identifier()),
+                );
+            }
+        }
+        for deconstruct in &item.deconstruct_items {
+            push(
+                ReferenceKind::Item,
+                &deconstruct.item_identifier,
+                format!("deconstruction of item {}", item.get_identifier()),
+            );
+        }
+    }
+    for set in files.start_items.iter().flat_map(|file| &file.sets) {
+        for start_item in &set.value.items {
+            push(
+                ReferenceKind::Item,
+                &start_item.identifier,
+                format!("start item set {}", set.value.identifier),
+            );
+        }
+    }
+    for set in files.npc_sets.iter().flat_map(|file| &file.sets) {
+        for human in &set.value.humans {
+            let referrer = format!("NPC {} of set {}", human.identifier, set.value.identifier);
+            //NPCs without a job get a random one
+            if !human.human_properties.job.as_str().is_empty() {
+                push(
+                    ReferenceKind::Job,
+                    &human.human_properties.job,
+                    referrer.clone(),
+                );
+            }
+            let mut items = human
+                .itemsets
+                .iter()
+                .flat_map(|(set, _)| &set.items)
+                .collect::<Vec<_>>();
+            while let Some(item) = items.pop() {
+                push(ReferenceKind::Item, &item.identifier, referrer.clone());
+                items.extend(&item.inner_items);
+            }
+        }
+    }
+    for job in files.jobs.iter().flat_map(|file| &file.jobs) {
+        for order in &job.value.appropriate_orders {
+            push(
+                ReferenceKind::Order,
+                order,
+                format!("job {}", job.value.identifier),
+            );
+        }
+    }
+    for location_type in files
+        .location_types
+        .iter()
+        .flat_map(|file| &file.location_types)
+    {
+        for mission in location_type.value.mission_identifiers.iter().flatten() {
+            push(
+                ReferenceKind::Mission,
+                mission,
+                format!("location type {}", location_type.value.identifier),
+            );
+        }
+    }
+    for mission in files
+        .mission_prefabs
+        .iter()
+        .flat_map(|file| &file.mission_prefabs)
+    {
+        for location_type in &mission.value.allowed_location_types {
+            push(
+                ReferenceKind::LocationType,
+                location_type,
+                format!("mission {}", mission.value.identifier),
+            );
+        }
+    }
+    for params in files
+        .outpost_configs
+        .iter()
+        .flat_map(|file| &file.outpost_generation_params)
+    {
+        for location_type in params.value.allowed_location_types.iter().flatten() {
+            //outposts allowed in any location type
+            if location_type == "any" {
+                continue;
+            }
+            push(
+                ReferenceKind::LocationType,
+                location_type,
+                format!("outpost generation params {}", params.value.identifier),
+            );
+        }
+    }
+    references
+}
+
+/// References to identifiers that no enabled package provides, grouped by the package making the reference
+pub fn find_dangling_references(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> Vec<PackageReferences> {
+    let defined = DefinedIdentifiers::new(packages.iter().map(|(_, files)| files));
+    packages
+        .iter()
+        .filter_map(|(package, files)| {
+            let references = references(files)
+                .into_iter()
+                .filter(|v| !defined.contains(v.kind, &v.identifier))
+                .collect::<Vec<_>>();
+            (!references.is_empty()).then(|| PackageReferences {
+                package: package.clone(),
+                references,
+            })
+        })
+        .collect()
+}
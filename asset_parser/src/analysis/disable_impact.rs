@@ -0,0 +1,217 @@
+use std::{fmt::Display, path::Path, sync::Arc};
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFilePaths, ContentFiles},
+    loading::{ConflictType, Conflicts},
+    shared::identifier::Identifier,
+};
+
+use super::{
+    PrefabProviders, SubmarineRef,
+    dangling_references::{DanglingReference, DefinedIdentifiers, PackageReferences, references},
+    missing_prefabs::{MissingPrefabs, count_missing},
+};
+
+#[derive(Debug, Clone)]
+pub enum ConflictChange {
+    /// Only one mod besides vanilla still defines the prefab
+    Resolved,
+    /// The disabled package was the last one to define the prefab, so another package wins now
+    WinnerChanged {
+        from: Arc<AnyContentPackage>,
+        to: Arc<AnyContentPackage>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct ConflictImpact {
+    pub conflict_type: ConflictType,
+    pub identifier: Identifier,
+    pub change: ConflictChange,
+}
+
+/// What changes in the effective content set when a package is disabled
+#[derive(Debug)]
+pub struct DisableImpact {
+    pub package: Arc<AnyContentPackage>,
+    pub conflicts: Vec<ConflictImpact>,
+    /// References of other packages that only the disabled package provided
+    pub dangling_references: Vec<PackageReferences>,
+    /// Submarines of other packages using prefabs only the disabled package provided
+    pub submarines: Vec<MissingPrefabs>,
+    /// Patch mod files resolving conflicts that no longer exist
+    pub obsolete_patch_files: Vec<String>,
+}
+
+impl Display for DisableImpact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Disabling {}:", self.package.package_id())?;
+        for conflict in &self.conflicts {
+            match &conflict.change {
+                ConflictChange::Resolved => writeln!(
+                    f,
+                    "  {} conflict {} is resolved",
+                    conflict.conflict_type, conflict.identifier
+                )?,
+                ConflictChange::WinnerChanged { from, to } => writeln!(
+                    f,
+                    "  {} {} is now defined by {} instead of {}",
+                    conflict.conflict_type,
+                    conflict.identifier,
+                    to.package_id(),
+                    from.package_id()
+                )?,
+            }
+        }
+        for package in &self.dangling_references {
+            for reference in &package.references {
+                writeln!(f, "  {}: {}", package.package.package_id(), reference)?;
+            }
+        }
+        for missing in &self.submarines {
+            writeln!(
+                f,
+                "  {} ({}) of {} loses items: {:?}, structures: {:?}",
+                missing.submarine.file_path,
+                missing.submarine.asset_type,
+                missing.submarine.package.package_id(),
+                missing.items,
+                missing.structures
+            )?;
+        }
+        for file in &self.obsolete_patch_files {
+            writeln!(f, "  Patch mod file {} becomes obsolete", file)?;
+        }
+        Ok(())
+    }
+}
+
+/// Conflict type and identifier of a patch mod file saved by the conflict solver as `files/<conflict type>/<identifier>.xml`
+fn patch_file_conflict(conflict_type: ConflictType, file_path: &str) -> Option<Identifier> {
+    let path = Path::new(file_path);
+    let directory = path.parent()?.file_name()?.to_str()?;
+    (directory == conflict_type.to_string())
+        .then(|| path.file_stem()?.to_str().map(Identifier::from))
+        .flatten()
+}
+
+/// Recomputes the effective content without the package with the given id (name or workshop id)
+pub fn disable_impact(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    conflicts: &Conflicts,
+    package_id: &str,
+    patch_mod: Option<&ContentFilePaths>,
+) -> Option<DisableImpact> {
+    let package = packages
+        .iter()
+        .map(|(package, _)| package)
+        .find(|v| v.package_id() == package_id || v.package_id_prefer_ugc_id() == package_id)?
+        .clone();
+    let remaining = packages
+        .iter()
+        .filter(|(v, _)| !Arc::ptr_eq(v, &package))
+        .collect::<Vec<_>>();
+
+    let mut impact = DisableImpact {
+        package: package.clone(),
+        conflicts: Vec::new(),
+        dangling_references: Vec::new(),
+        submarines: Vec::new(),
+        obsolete_patch_files: Vec::new(),
+    };
+
+    for conflict_type in ConflictType::iter() {
+        let mut sorted = conflict_type
+            .get_conflict_by_type(conflicts)
+            .iter()
+            .filter(|(_, v)| v.added_by.iter().any(|v| Arc::ptr_eq(v, &package)))
+            .collect::<Vec<_>>();
+        sorted.sort_by(|a, b| a.0.cmp(b.0));
+        for (identifier, entry) in sorted {
+            let added_by = entry
+                .added_by
+                .iter()
+                .filter(|v| !Arc::ptr_eq(v, &package))
+                .collect::<Vec<_>>();
+            //same threshold as the conflict detection while loading
+            let change = if added_by.len() <= 2 {
+                ConflictChange::Resolved
+            } else if let (Some(from), Some(to)) = (entry.added_by.last(), added_by.last())
+                && Arc::ptr_eq(from, &package)
+            {
+                ConflictChange::WinnerChanged {
+                    from: from.clone(),
+                    to: (*to).clone(),
+                }
+            } else {
+                continue;
+            };
+            impact.conflicts.push(ConflictImpact {
+                conflict_type,
+                identifier: identifier.clone(),
+                change,
+            });
+        }
+    }
+
+    if let Some(patch_mod) = patch_mod {
+        for conflict in impact
+            .conflicts
+            .iter()
+            .filter(|v| matches!(v.change, ConflictChange::Resolved))
+        {
+            impact.obsolete_patch_files.extend(
+                conflict
+                    .conflict_type
+                    .get_conflict_file_paths_by_type(patch_mod)
+                    .iter()
+                    .filter(|v| {
+                        patch_file_conflict(conflict.conflict_type, v).as_ref()
+                            == Some(&conflict.identifier)
+                    })
+                    .cloned(),
+            );
+        }
+    }
+
+    let defined_before = DefinedIdentifiers::new(packages.iter().map(|(_, files)| files));
+    let defined_after = DefinedIdentifiers::new(remaining.iter().map(|(_, files)| files));
+    let before = PrefabProviders::new(packages);
+    let after = PrefabProviders::new(remaining.iter().copied());
+    for (other, files) in &remaining {
+        let references = references(files)
+            .into_iter()
+            .filter(|v| {
+                defined_before.contains(v.kind, &v.identifier)
+                    && !defined_after.contains(v.kind, &v.identifier)
+            })
+            .collect::<Vec<DanglingReference>>();
+        if !references.is_empty() {
+            impact.dangling_references.push(PackageReferences {
+                package: other.clone(),
+                references,
+            });
+        }
+
+        for (asset_type, asset) in files.submarine_assets() {
+            let info = &asset.sub.submarine_info;
+            let items = count_missing(info.items.iter().map(|v| &v.identifier), |v| {
+                after.items.contains_key(v) || !before.items.contains_key(v)
+            });
+            let structures = count_missing(info.structures.iter().map(|v| &v.identifier), |v| {
+                after.structures.contains_key(v) || !before.structures.contains_key(v)
+            });
+            if !items.is_empty() || !structures.is_empty() {
+                impact.submarines.push(MissingPrefabs {
+                    submarine: SubmarineRef::new(other, asset_type, asset),
+                    items,
+                    structures,
+                });
+            }
+        }
+    }
+
+    Some(impact)
+}
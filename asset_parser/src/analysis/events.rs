@@ -0,0 +1,360 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    sync::Arc,
+};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{
+        identifier::Identifier,
+        prefabs::{
+            event_action::{EventAction, EventActionKind},
+            event_prefab::{EventPrefab, EventSet, PrefabOrIdentifiers},
+        },
+    },
+};
+
+#[derive(Debug, Clone)]
+pub enum EventIssueKind {
+    UndefinedLabel(Identifier),
+    MissingItem(Identifier),
+    MissingMission(Identifier),
+    MissingCharacter(Identifier),
+    MissingNpcSet(Identifier),
+    MissingNpc {
+        set: Identifier,
+        npc: Identifier,
+    },
+    MissingEvent(Identifier),
+    MissingTextTag(String),
+    /// Actions following a `GoTo` or `End` before any label jumped to, named by the first of them
+    Unreachable(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct EventIssue {
+    pub package: Arc<AnyContentPackage>,
+    /// Event or event set the issue is in, e.g. `event clownevent`
+    pub referrer: String,
+    pub kind: EventIssueKind,
+}
+
+impl Display for EventIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} of {}: ", self.referrer, self.package.package_id())?;
+        match &self.kind {
+            EventIssueKind::UndefinedLabel(label) => write!(f, "goto to undefined label {}", label),
+            EventIssueKind::MissingItem(item) => write!(f, "references undefined item {}", item),
+            EventIssueKind::MissingMission(mission) => {
+                write!(f, "references undefined mission {}", mission)
+            }
+            EventIssueKind::MissingCharacter(species) => {
+                write!(f, "spawns undefined character {}", species)
+            }
+            EventIssueKind::MissingNpcSet(set) => {
+                write!(f, "references undefined NPC set {}", set)
+            }
+            EventIssueKind::MissingNpc { set, npc } => {
+                write!(
+                    f,
+                    "references NPC {} which NPC set {} doesn't have",
+                    npc, set
+                )
+            }
+            EventIssueKind::MissingEvent(event) => {
+                write!(f, "references undefined event {}", event)
+            }
+            EventIssueKind::MissingTextTag(tag) => {
+                write!(f, "references undefined text tag {}", tag)
+            }
+            EventIssueKind::Unreachable(action) => {
+                write!(f, "{} action can never be reached", action)
+            }
+        }
+    }
+}
+
+/// Identifiers events can reference, defined by any enabled package
+struct EventReferences<'a> {
+    items: HashSet<&'a Identifier>,
+    missions: HashSet<&'a Identifier>,
+    species: HashSet<&'a Identifier>,
+    npc_sets: HashMap<&'a Identifier, HashSet<&'a Identifier>>,
+    events: HashSet<&'a Identifier>,
+    text_tags: HashSet<&'a str>,
+}
+
+fn set_events<'a>(set: &'a EventSet, events: &mut HashSet<&'a Identifier>) {
+    for prefab in &set.event_prefabs {
+        if let PrefabOrIdentifiers::Prefab(prefab) = &prefab.prefab_or_identifiers {
+            events.insert(&prefab.identifier);
+        }
+    }
+    for child in &set.child_sets {
+        set_events(child, events);
+    }
+}
+
+impl<'a> EventReferences<'a> {
+    fn new(packages: &'a [(Arc<AnyContentPackage>, ContentFiles)]) -> Self {
+        let mut references = Self {
+            items: HashSet::new(),
+            missions: HashSet::new(),
+            species: HashSet::new(),
+            npc_sets: HashMap::new(),
+            events: HashSet::new(),
+            text_tags: HashSet::new(),
+        };
+        for (_, files) in packages {
+            for item in files.items.iter().flat_map(|file| &file.items) {
+                references.items.insert(item.value.get_identifier());
+            }
+            for mission in files
+                .mission_prefabs
+                .iter()
+                .flat_map(|file| &file.mission_prefabs)
+            {
+                references.missions.insert(&mission.value.identifier);
+            }
+            for file in &files.characters {
+                references.species.extend(&file.character.species_name);
+            }
+            for set in files.npc_sets.iter().flat_map(|file| &file.sets) {
+                references
+                    .npc_sets
+                    .entry(&set.value.identifier)
+                    .or_default()
+                    .extend(set.value.humans.iter().map(|v| &v.identifier));
+            }
+            for file in &files.random_events {
+                references
+                    .events
+                    .extend(file.event_prefabs.iter().map(|v| &v.value.identifier));
+                references.events.extend(
+                    file.traitor_event_prefabs
+                        .iter()
+                        .map(|v| &v.value.event_prefab.identifier),
+                );
+                for set in &file.event_sets {
+                    set_events(&set.value, &mut references.events);
+                }
+            }
+            for file in &files.texts {
+                references
+                    .text_tags
+                    .extend(file.texts.keys().map(String::as_str));
+            }
+        }
+        references
+    }
+}
+
+/// Texts are looked up as tags first and used as is when no such tag exists, so only texts shaped like tags are checked
+fn is_text_tag(text: &str) -> bool {
+    !text.is_empty() && text.contains('.') && !text.contains(char::is_whitespace)
+}
+
+fn visit<'a>(actions: &'a [EventAction], f: &mut impl FnMut(&'a EventAction)) {
+    for action in actions {
+        f(action);
+        for branch in &action.branches {
+            visit(&branch.actions, f);
+        }
+    }
+}
+
+fn find_unreachable<'a>(
+    actions: &'a [EventAction],
+    targets: &HashSet<&Identifier>,
+    result: &mut Vec<&'a EventAction>,
+) {
+    let mut reachable = true;
+    let mut reported = false;
+    for action in actions {
+        if let EventActionKind::Label { name } = &action.kind {
+            if targets.contains(name) {
+                reachable = true;
+                reported = false;
+            }
+            continue;
+        }
+        if !reachable {
+            if !reported {
+                result.push(action);
+                reported = true;
+            }
+            continue;
+        }
+        for branch in &action.branches {
+            find_unreachable(&branch.actions, targets, result);
+        }
+        if matches!(
+            action.kind,
+            EventActionKind::GoTo { .. } | EventActionKind::End
+        ) {
+            reachable = false;
+        }
+    }
+}
+
+fn check_event(
+    event: &EventPrefab,
+    references: &EventReferences,
+    mut push: impl FnMut(EventIssueKind),
+) {
+    let mut labels = HashSet::new();
+    let mut targets = HashSet::new();
+    visit(&event.actions, &mut |action| match &action.kind {
+        EventActionKind::Label { name } => {
+            labels.insert(name);
+        }
+        EventActionKind::GoTo { name } => {
+            targets.insert(name);
+        }
+        _ => {}
+    });
+    for target in &targets {
+        if !labels.contains(target) {
+            push(EventIssueKind::UndefinedLabel((*target).clone()));
+        }
+    }
+
+    visit(&event.actions, &mut |action| {
+        let mut texts = action
+            .branches
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect::<Vec<_>>();
+        match &action.kind {
+            EventActionKind::Conversation {
+                text: Some(text), ..
+            }
+            | EventActionKind::EventLog { text: Some(text) } => texts.push(text),
+            EventActionKind::MessageBox { header, text } => {
+                texts.extend(header.iter().chain(text).map(String::as_str))
+            }
+            EventActionKind::Spawn {
+                species_name,
+                npc_set,
+                npc,
+                item,
+                ..
+            } => {
+                if let Some(species) = species_name
+                    && !references.species.contains(species)
+                {
+                    push(EventIssueKind::MissingCharacter(species.clone()));
+                }
+                if let Some(set) = npc_set {
+                    match references.npc_sets.get(set) {
+                        None => push(EventIssueKind::MissingNpcSet(set.clone())),
+                        Some(humans) => {
+                            if let Some(npc) = npc
+                                && !humans.contains(npc)
+                            {
+                                push(EventIssueKind::MissingNpc {
+                                    set: set.clone(),
+                                    npc: npc.clone(),
+                                });
+                            }
+                        }
+                    }
+                }
+                if let Some(item) = item
+                    && !references.items.contains(item)
+                {
+                    push(EventIssueKind::MissingItem(item.clone()));
+                }
+            }
+            EventActionKind::Mission {
+                mission: Some(mission),
+                ..
+            } if !references.missions.contains(mission) => {
+                push(EventIssueKind::MissingMission(mission.clone()))
+            }
+            EventActionKind::CheckItem { items, .. }
+            | EventActionKind::RemoveItem { items, .. } => {
+                for item in items.iter().filter(|v| !references.items.contains(v)) {
+                    push(EventIssueKind::MissingItem(item.clone()));
+                }
+            }
+            EventActionKind::TriggerEvent {
+                identifier: Some(identifier),
+            } if !references.events.contains(identifier) => {
+                push(EventIssueKind::MissingEvent(identifier.clone()))
+            }
+            _ => {}
+        }
+        for text in texts {
+            if is_text_tag(text) && !references.text_tags.contains(text.to_lowercase().as_str()) {
+                push(EventIssueKind::MissingTextTag(text.to_owned()));
+            }
+        }
+    });
+
+    let mut unreachable = Vec::new();
+    find_unreachable(&event.actions, &targets, &mut unreachable);
+    for action in unreachable {
+        push(EventIssueKind::Unreachable(action.kind.name().to_owned()));
+    }
+}
+
+fn check_event_set(
+    set: &EventSet,
+    references: &EventReferences,
+    push: &mut impl FnMut(String, EventIssueKind),
+) {
+    for prefab in &set.event_prefabs {
+        match &prefab.prefab_or_identifiers {
+            PrefabOrIdentifiers::Prefab(event) => {
+                check_event(event, references, |kind| {
+                    push(format!("event {}", event.identifier), kind)
+                });
+            }
+            PrefabOrIdentifiers::Identifiers(identifiers) => {
+                for identifier in identifiers {
+                    if !references.events.contains(identifier) {
+                        push(
+                            format!("event set {}", set.identifier),
+                            EventIssueKind::MissingEvent(identifier.clone()),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    for child in &set.child_sets {
+        check_event_set(child, references, push);
+    }
+}
+
+/// Broken references and control flow of scripted events and event sets
+pub fn check_events(packages: &[(Arc<AnyContentPackage>, ContentFiles)]) -> Vec<EventIssue> {
+    let references = EventReferences::new(packages);
+    let mut issues = Vec::new();
+    for (package, files) in packages {
+        let mut push = |referrer, kind| {
+            issues.push(EventIssue {
+                package: package.clone(),
+                referrer,
+                kind,
+            })
+        };
+        for file in &files.random_events {
+            for event in file.event_prefabs.iter().map(|v| &v.value).chain(
+                file.traitor_event_prefabs
+                    .iter()
+                    .map(|v| &v.value.event_prefab),
+            ) {
+                check_event(event, &references, |kind| {
+                    push(format!("event {}", event.identifier), kind)
+                });
+            }
+            for set in &file.event_sets {
+                check_event_set(&set.value, &references, &mut push);
+            }
+        }
+    }
+    issues
+}
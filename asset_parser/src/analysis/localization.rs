@@ -0,0 +1,325 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt::Display,
+    sync::Arc,
+};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::identifier::Identifier,
+};
+
+/// Language the game falls back to when a text is missing in the selected one
+const FALLBACK_LANGUAGE: &str = "English";
+
+/// Tag prefixes the game builds from prefab identifiers, tags with these are expected to match a prefab
+const PREFAB_TAG_PREFIXES: [&str; 8] = [
+    "entityname.",
+    "entitydescription.",
+    "talentname.",
+    "talenttree.",
+    "missionname.",
+    "missiondescription.",
+    "afflictionname.",
+    "jobname.",
+];
+
+/// Text tags a prefab uses, any of `tags` is enough for the text to show up
+#[derive(Debug, Clone)]
+pub struct TextReference {
+    /// Lowercase like the tags of text files
+    pub tags: Vec<String>,
+    /// Prefab using the text, e.g. `item fabricator`
+    pub referrer: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextFallback {
+    /// The English text is shown instead
+    English,
+    /// The tag itself or the prefab's name attribute is shown
+    RawTag,
+}
+
+#[derive(Debug, Clone)]
+pub struct MissingTranslation {
+    pub reference: TextReference,
+    pub fallback: TextFallback,
+}
+
+#[derive(Debug, Clone)]
+pub struct LanguageCoverage {
+    pub language: String,
+    pub referenced: usize,
+    pub missing: Vec<MissingTranslation>,
+}
+
+impl LanguageCoverage {
+    /// Share of referenced texts that have a translation, 1.0 when nothing is referenced
+    pub fn coverage(&self) -> f32 {
+        if self.referenced == 0 {
+            return 1.0;
+        }
+        1.0 - self.missing.len() as f32 / self.referenced as f32
+    }
+}
+
+/// Text coverage of the prefabs a package defines and the prefab text tags it ships without a matching prefab
+#[derive(Debug)]
+pub struct PackageLocalization {
+    pub package: Arc<AnyContentPackage>,
+    pub languages: Vec<LanguageCoverage>,
+    /// Language and tag
+    pub unused_tags: Vec<(String, String)>,
+}
+
+impl Display for PackageLocalization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}:", self.package.package_id())?;
+        for language in &self.languages {
+            writeln!(
+                f,
+                "  {}: {}/{} texts ({:.0}%)",
+                language.language,
+                language.referenced - language.missing.len(),
+                language.referenced,
+                language.coverage() * 100.0
+            )?;
+            for missing in &language.missing {
+                writeln!(
+                    f,
+                    "    {} of {} is missing, {}",
+                    missing.reference.tags.join(" or "),
+                    missing.reference.referrer,
+                    match missing.fallback {
+                        TextFallback::English => "English is shown instead",
+                        TextFallback::RawTag => "the raw tag is shown instead",
+                    }
+                )?;
+            }
+        }
+        for (language, tag) in &self.unused_tags {
+            writeln!(f, "  Unused {} tag {}", language, tag)?;
+        }
+        Ok(())
+    }
+}
+
+fn tag(prefix: &str, identifier: &Identifier) -> String {
+    format!("{}{}", prefix, identifier.to_lowercase())
+}
+
+/// Text tags the prefabs of a package reference, built like the game does from identifiers and their overrides
+pub fn text_references(files: &ContentFiles) -> Vec<TextReference> {
+    let mut references = Vec::new();
+    let mut push =
+        |tags: Vec<String>, referrer: String| references.push(TextReference { tags, referrer });
+
+    for item in files.items.iter().flat_map(|file| &file.items) {
+        let item = &item.value;
+        let mut names = vec![tag(
+            "entityname.",
+            item.name_identifier.as_ref().unwrap_or(&item.identifier),
+        )];
+        names.extend(
+            item.fallback_name_identifier
+                .iter()
+                .map(|v| tag("entityname.", v)),
+        );
+        push(names, format!("item {}", item.identifier));
+        //descriptions are looked up by identifier when no description identifier is set
+        push(
+            vec![tag(
+                "entitydescription.",
+                item.description_identifier
+                    .as_ref()
+                    .unwrap_or(&item.identifier),
+            )],
+            format!("item {}", item.identifier),
+        );
+    }
+    for structure in files.structures.iter().flat_map(|file| &file.prefabs) {
+        let structure = &structure.value;
+        let mut names = vec![tag(
+            "entityname.",
+            structure
+                .name_identifier
+                .as_ref()
+                .unwrap_or(&structure.identifier),
+        )];
+        names.extend(
+            structure
+                .fallback_name_identifier
+                .iter()
+                .map(|v| tag("entityname.", v)),
+        );
+        push(names, format!("structure {}", structure.identifier));
+        //descriptions are looked up by identifier when no description identifier is set
+        push(
+            vec![tag(
+                "entitydescription.",
+                structure
+                    .description_identifier
+                    .as_ref()
+                    .unwrap_or(&structure.identifier),
+            )],
+            format!("structure {}", structure.identifier),
+        );
+    }
+    for talent in files.talents.iter().flat_map(|file| &file.items) {
+        let talent = &talent.value;
+        push(
+            vec![tag(
+                "talentname.",
+                talent
+                    .name_identifier
+                    .as_ref()
+                    .unwrap_or(&talent.identifier),
+            )],
+            format!("talent {}", talent.identifier),
+        );
+    }
+    for tree in files.talent_trees.iter().flat_map(|file| &file.trees) {
+        for subtree in &tree.value.sub_trees {
+            //the name identifier of a subtree is the whole tag
+            push(
+                vec![match &subtree.name_identifier {
+                    Some(name) => name.to_lowercase(),
+                    None => tag("talenttree.", &subtree.identifier),
+                }],
+                format!("talent subtree {}", subtree.identifier),
+            );
+        }
+    }
+    for mission in files
+        .mission_prefabs
+        .iter()
+        .flat_map(|file| &file.mission_prefabs)
+    {
+        let mission = &mission.value;
+        let referrer = format!("mission {}", mission.identifier);
+        push(
+            vec![tag("missionname.", &mission.text_identifier)],
+            referrer.clone(),
+        );
+        push(
+            vec![tag("missiondescription.", &mission.text_identifier)],
+            referrer.clone(),
+        );
+        for message in [
+            &mission.success_message_tag,
+            &mission.failure_message_tag,
+            &mission.sonar_label_tag,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            push(vec![message.to_lowercase()], referrer.clone());
+        }
+    }
+    for affliction in files
+        .afflictions
+        .iter()
+        .flat_map(|file| &file.affliction_prefabs)
+    {
+        let affliction = &affliction.value;
+        push(
+            vec![tag(
+                "afflictionname.",
+                affliction
+                    .translation_identifier
+                    .as_ref()
+                    .unwrap_or(&affliction.identifier),
+            )],
+            format!("affliction {}", affliction.identifier),
+        );
+    }
+    for job in files.jobs.iter().flat_map(|file| &file.jobs) {
+        push(
+            vec![tag("jobname.", &job.value.identifier)],
+            format!("job {}", job.value.identifier),
+        );
+    }
+    references
+}
+
+/// Coverage of the languages a package ships texts in and the fallback language, translations can come from any package
+pub fn localization_coverage(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> Vec<PackageLocalization> {
+    let mut texts: BTreeMap<&str, HashSet<&str>> = BTreeMap::new();
+    for (_, files) in packages {
+        for file in &files.texts {
+            texts
+                .entry(file.language_name.as_str())
+                .or_default()
+                .extend(file.texts.keys().map(String::as_str));
+        }
+    }
+    let fallback = texts.get(FALLBACK_LANGUAGE);
+
+    let references = packages
+        .iter()
+        .map(|(_, files)| text_references(files))
+        .collect::<Vec<_>>();
+    let used = references
+        .iter()
+        .flatten()
+        .flat_map(|v| v.tags.iter().cloned())
+        .collect::<HashSet<_>>();
+
+    packages
+        .iter()
+        .zip(&references)
+        .map(|((package, files), references)| {
+            let shipped = files
+                .texts
+                .iter()
+                .map(|file| file.language_name.as_str())
+                .chain([FALLBACK_LANGUAGE])
+                .collect::<HashSet<_>>();
+            let languages = texts
+                .iter()
+                .filter(|(language, _)| shipped.contains(*language))
+                .map(|(language, tags)| LanguageCoverage {
+                    language: (*language).to_owned(),
+                    referenced: references.len(),
+                    missing: references
+                        .iter()
+                        .filter(|v| !v.tags.iter().any(|tag| tags.contains(tag.as_str())))
+                        .map(|v| MissingTranslation {
+                            reference: v.clone(),
+                            fallback: if fallback
+                                .is_some_and(|f| v.tags.iter().any(|tag| f.contains(tag.as_str())))
+                            {
+                                TextFallback::English
+                            } else {
+                                TextFallback::RawTag
+                            },
+                        })
+                        .collect(),
+                })
+                .collect();
+            let unused_tags = files
+                .texts
+                .iter()
+                .flat_map(|file| {
+                    file.texts
+                        .keys()
+                        .filter(|tag| {
+                            PREFAB_TAG_PREFIXES.iter().any(|v| tag.starts_with(v))
+                                && !used.contains(tag.as_str())
+                        })
+                        .map(|tag| (file.language_name.clone(), tag.clone()))
+                })
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            PackageLocalization {
+                package: package.clone(),
+                languages,
+                unused_tags,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,56 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::identifier::Identifier,
+};
+
+use super::{PrefabProviders, SubmarineRef};
+
+/// Items and structures placed in a submarine that no enabled package defines, with the amount of placed instances
+#[derive(Debug)]
+pub struct MissingPrefabs {
+    pub submarine: SubmarineRef,
+    pub items: Vec<(Identifier, usize)>,
+    pub structures: Vec<(Identifier, usize)>,
+}
+
+pub(super) fn count_missing<'a>(
+    identifiers: impl Iterator<Item = &'a Identifier>,
+    is_defined: impl Fn(&Identifier) -> bool,
+) -> Vec<(Identifier, usize)> {
+    let mut missing: BTreeMap<&Identifier, usize> = BTreeMap::new();
+    for identifier in identifiers.filter(|v| !is_defined(v)) {
+        *missing.entry(identifier).or_default() += 1;
+    }
+    missing
+        .into_iter()
+        .map(|(identifier, count)| (identifier.clone(), count))
+        .collect()
+}
+
+pub fn find_missing_prefabs(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> Vec<MissingPrefabs> {
+    let providers = PrefabProviders::new(packages);
+    let mut result = Vec::new();
+    for (package, files) in packages {
+        for (asset_type, asset) in files.submarine_assets() {
+            let info = &asset.sub.submarine_info;
+            let items = count_missing(info.items.iter().map(|v| &v.identifier), |v| {
+                providers.items.contains_key(v)
+            });
+            let structures = count_missing(info.structures.iter().map(|v| &v.identifier), |v| {
+                providers.structures.contains_key(v)
+            });
+            if !items.is_empty() || !structures.is_empty() {
+                result.push(MissingPrefabs {
+                    submarine: SubmarineRef::new(package, asset_type, asset),
+                    items,
+                    structures,
+                });
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,266 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    sync::Arc,
+};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{
+        identifier::Identifier,
+        prefabs::{
+            item_prefab::{CommonnessInfo, ItemPrefab},
+            job_prefab::JobItem,
+            mission_prefab::{ItemIdentifierOrTag, MissionTypeSpecific, SalvageTargetWithAmount},
+        },
+    },
+};
+
+use super::variants::ResolvedItems;
+
+#[derive(Debug, Clone)]
+pub enum ItemSource {
+    /// Materials are identifiers or tags, no fabricators means any fabricator
+    Fabricate {
+        materials: Vec<Identifier>,
+        fabricators: Vec<Identifier>,
+    },
+    Deconstruct {
+        from: Identifier,
+    },
+    /// Sold in a store with its own price info, or in any store through the default price
+    Store {
+        store: Option<Identifier>,
+    },
+    StartItems {
+        set: Identifier,
+    },
+    JobItems {
+        job: Identifier,
+    },
+    LevelResource,
+    /// Placed in containers with any of the tags when outposts, wrecks and submarines are populated
+    ContainerLoot {
+        containers: Vec<String>,
+    },
+    /// Target of a salvage mission
+    Mission {
+        mission: Identifier,
+    },
+}
+
+impl Display for ItemSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemSource::Fabricate {
+                materials,
+                fabricators,
+            } if fabricators.is_empty() => write!(f, "fabricated from {:?}", materials),
+            ItemSource::Fabricate {
+                materials,
+                fabricators,
+            } => write!(f, "fabricated from {:?} in {:?}", materials, fabricators),
+            ItemSource::Deconstruct { from } => write!(f, "deconstructed from {}", from),
+            ItemSource::Store { store: Some(store) } => write!(f, "sold in store {}", store),
+            ItemSource::Store { store: None } => write!(f, "sold in stores"),
+            ItemSource::StartItems { set } => write!(f, "start item set {}", set),
+            ItemSource::JobItems { job } => write!(f, "items of job {}", job),
+            ItemSource::LevelResource => write!(f, "spawned in levels"),
+            ItemSource::ContainerLoot { containers } => {
+                write!(f, "spawned in containers tagged {:?}", containers)
+            }
+            ItemSource::Mission { mission } => write!(f, "salvaged in mission {}", mission),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ItemObtainability {
+    pub item: Identifier,
+    /// Package the effective definition of the item comes from
+    pub package: Arc<AnyContentPackage>,
+    pub sources: Vec<ItemSource>,
+}
+
+impl ItemObtainability {
+    pub fn is_obtainable(&self) -> bool {
+        !self.sources.is_empty()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ObtainabilityReport {
+    pub items: Vec<ItemObtainability>,
+}
+
+impl ObtainabilityReport {
+    /// Items with no known way for a player to get them
+    pub fn unobtainable(&self) -> impl Iterator<Item = &ItemObtainability> {
+        self.items.iter().filter(|v| !v.is_obtainable())
+    }
+}
+
+fn has_commonness(info: &CommonnessInfo) -> bool {
+    [info.commonness, info.abyss_commonness, info.cave_commonness]
+        .into_iter()
+        .flatten()
+        .any(|v| v > 0.0)
+}
+
+fn is_level_resource(item: &ItemPrefab) -> bool {
+    item.default_level_commonness
+        .as_ref()
+        .is_some_and(has_commonness)
+        || item.level_commonness.values().any(has_commonness)
+        || item.default_level_quantity.is_some()
+        || !item.level_quantity.is_empty()
+}
+
+fn salvage_targets<'a>(targets: &'a [Vec<SalvageTargetWithAmount>], result: &mut Vec<&'a str>) {
+    for target in targets.iter().flatten() {
+        if let Some(ItemIdentifierOrTag::Identifier(identifier)) = &target.target.item_prefab_ident
+        {
+            result.push(identifier);
+        }
+        salvage_targets(&target.internal_targets, result);
+    }
+}
+
+pub fn find_item_sources(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    resolved: &ResolvedItems,
+) -> ObtainabilityReport {
+    let items = resolved.items();
+    let mut sources: HashMap<Identifier, Vec<ItemSource>> = HashMap::new();
+    let mut add = |identifier: &Identifier, source| {
+        sources.entry(identifier.clone()).or_default().push(source)
+    };
+
+    for (identifier, (_, item)) in &items {
+        for recipe in &item.fabrication_recipes {
+            add(
+                identifier,
+                ItemSource::Fabricate {
+                    materials: recipe
+                        .required_items
+                        .iter()
+                        .map(|v| v.target().clone())
+                        .collect(),
+                    fabricators: recipe.suitable_fabricators.clone().unwrap_or_default(),
+                },
+            );
+        }
+        for output in &item.deconstruct_items {
+            add(
+                &output.item_identifier,
+                ItemSource::Deconstruct {
+                    from: (*identifier).clone(),
+                },
+            );
+        }
+        if item.default_price.as_ref().is_some_and(|v| v.can_be_bought) {
+            add(identifier, ItemSource::Store { store: None });
+        }
+        for (store, price) in &item.store_prices {
+            if price.can_be_bought {
+                add(
+                    identifier,
+                    ItemSource::Store {
+                        store: Some(store.clone()),
+                    },
+                );
+            }
+        }
+        if is_level_resource(item) {
+            add(identifier, ItemSource::LevelResource);
+        }
+        //like the game, only containers with a spawn chance or amount get the item placed in them
+        for container in item.preferred_containers.iter().filter(|v| {
+            v.spawn_probability.is_some_and(|v| v > 0.0)
+                || v.amount.is_some_and(|v| v > 0)
+                || v.max_amount.is_some_and(|v| v > 0)
+        }) {
+            add(
+                identifier,
+                ItemSource::ContainerLoot {
+                    containers: container.primary.clone().unwrap_or_default(),
+                },
+            );
+        }
+    }
+
+    //sets, jobs and missions are overridden as a whole, the last definition wins
+    let mut start_item_sets = BTreeMap::new();
+    let mut jobs = BTreeMap::new();
+    let mut missions = BTreeMap::new();
+    for (_, files) in packages {
+        for set in files.start_items.iter().flat_map(|file| &file.sets) {
+            start_item_sets.insert(&set.value.identifier, &set.value);
+        }
+        for job in files.jobs.iter().flat_map(|file| &file.jobs) {
+            jobs.insert(&job.value.identifier, &job.value);
+        }
+        for mission in files
+            .mission_prefabs
+            .iter()
+            .flat_map(|file| &file.mission_prefabs)
+        {
+            missions.insert(&mission.value.identifier, &mission.value);
+        }
+    }
+    for (identifier, set) in start_item_sets {
+        for item in &set.items {
+            add(
+                &item.identifier,
+                ItemSource::StartItems {
+                    set: identifier.clone(),
+                },
+            );
+        }
+    }
+    for (identifier, job) in jobs {
+        let mut job_items = job
+            .item_sets
+            .iter()
+            .flat_map(|v| &v.items)
+            .collect::<Vec<&JobItem>>();
+        while let Some(item) = job_items.pop() {
+            if let Some(item_identifier) = &item.identifier {
+                add(
+                    item_identifier,
+                    ItemSource::JobItems {
+                        job: identifier.clone(),
+                    },
+                );
+            }
+            job_items.extend(&item.child_items);
+        }
+    }
+    for (identifier, mission) in missions {
+        let MissionTypeSpecific::Salvage(salvage) = &mission.mission_type_specific_properties
+        else {
+            continue;
+        };
+        let mut targets = Vec::new();
+        salvage_targets(&salvage.targets, &mut targets);
+        for target in targets {
+            add(
+                &Identifier::from(target),
+                ItemSource::Mission {
+                    mission: identifier.clone(),
+                },
+            );
+        }
+    }
+
+    ObtainabilityReport {
+        items: items
+            .into_iter()
+            .map(|(identifier, (package, _))| ItemObtainability {
+                item: identifier.clone(),
+                package: package.clone(),
+                sources: sources.remove(identifier).unwrap_or_default(),
+            })
+            .collect(),
+    }
+}
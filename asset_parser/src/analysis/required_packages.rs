@@ -0,0 +1,172 @@
+use std::{path::PathBuf, sync::Arc};
+
+use crate::{
+    content_file::{SubmarineAsset, SubmarineAssetType},
+    content_package::{AnyContentPackage, ContentFiles, ContentPackage, Regular},
+};
+
+use super::PrefabProviders;
+
+#[derive(Debug, Clone)]
+pub enum RequirementStatus {
+    Enabled(Arc<AnyContentPackage>),
+    /// Enabled, but matched through an alt name or a name with different casing
+    EnabledUnderDifferentName(Arc<AnyContentPackage>),
+    /// Installed but not in the enabled mod list
+    Disabled(PathBuf),
+    Missing,
+}
+
+#[derive(Debug)]
+pub struct RequiredPackagesReport {
+    /// None for standalone .sub files
+    pub package: Option<Arc<AnyContentPackage>>,
+    pub asset_type: Option<SubmarineAssetType>,
+    pub file_path: String,
+    pub requirements: Vec<(String, RequirementStatus)>,
+    /// Non-vanilla packages defining the items and structures the submarine uses
+    pub needed_packages: Vec<Arc<AnyContentPackage>>,
+    /// Needed packages that aren't listed in the submarine's required packages
+    pub unlisted_packages: Vec<Arc<AnyContentPackage>>,
+}
+
+impl RequiredPackagesReport {
+    pub fn has_issues(&self) -> bool {
+        !self.unlisted_packages.is_empty()
+            || self
+                .requirements
+                .iter()
+                .any(|(_, status)| !matches!(status, RequirementStatus::Enabled(_)))
+    }
+}
+
+fn name_matches(
+    name: &str,
+    package_name: &Option<String>,
+    alt_names: &Option<Vec<String>>,
+) -> bool {
+    package_name
+        .iter()
+        .chain(alt_names.iter().flatten())
+        .any(|v| v.trim().eq_ignore_ascii_case(name))
+}
+
+fn requirement_status(
+    name: &str,
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    installed_packages: &[(ContentPackage<Regular>, PathBuf)],
+) -> RequirementStatus {
+    if let Some((package, _)) = packages
+        .iter()
+        .find(|(package, _)| package.name().as_deref() == Some(name))
+    {
+        return RequirementStatus::Enabled(package.clone());
+    }
+    if let Some((package, _)) = packages
+        .iter()
+        .find(|(package, _)| name_matches(name, package.name(), package.alt_names()))
+    {
+        return RequirementStatus::EnabledUnderDifferentName(package.clone());
+    }
+    if let Some((_, path)) = installed_packages
+        .iter()
+        .find(|(package, _)| name_matches(name, &package.name, &package.alt_names))
+    {
+        return RequirementStatus::Disabled(path.clone());
+    }
+    RequirementStatus::Missing
+}
+
+struct RequirementContext<'a> {
+    packages: &'a [(Arc<AnyContentPackage>, ContentFiles)],
+    installed_packages: &'a [(ContentPackage<Regular>, PathBuf)],
+    providers: PrefabProviders,
+}
+
+impl RequirementContext<'_> {
+    fn check_submarine(
+        &self,
+        asset: &SubmarineAsset,
+        package: Option<&Arc<AnyContentPackage>>,
+        asset_type: Option<SubmarineAssetType>,
+    ) -> RequiredPackagesReport {
+        let info = &asset.sub.submarine_info;
+        let required = info
+            .required_content_packages
+            .as_deref()
+            .unwrap_or_default();
+        let requirements = required
+            .iter()
+            .map(|name| {
+                (
+                    name.clone(),
+                    requirement_status(name, self.packages, self.installed_packages),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut needed_packages: Vec<Arc<AnyContentPackage>> = Vec::new();
+        let used = info
+            .items
+            .iter()
+            .filter_map(|v| self.providers.items.get(&v.identifier))
+            .chain(
+                info.structures
+                    .iter()
+                    .filter_map(|v| self.providers.structures.get(&v.identifier)),
+            );
+        for provider in used {
+            if matches!(**provider, AnyContentPackage::Core(_))
+                || package.is_some_and(|v| Arc::ptr_eq(v, provider))
+                || needed_packages.iter().any(|v| Arc::ptr_eq(v, provider))
+            {
+                continue;
+            }
+            needed_packages.push(provider.clone());
+        }
+        let unlisted_packages = needed_packages
+            .iter()
+            .filter(|provider| {
+                !required
+                    .iter()
+                    .any(|name| name_matches(name, provider.name(), provider.alt_names()))
+            })
+            .cloned()
+            .collect();
+
+        RequiredPackagesReport {
+            package: package.cloned(),
+            asset_type,
+            file_path: asset.file_path.clone(),
+            requirements,
+            needed_packages,
+            unlisted_packages,
+        }
+    }
+}
+
+/// Checks the required content packages of every loaded submarine asset and of the given standalone .sub files
+pub fn check_required_packages(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    installed_packages: &[(ContentPackage<Regular>, PathBuf)],
+    sub_files: &[PathBuf],
+) -> Vec<RequiredPackagesReport> {
+    let context = RequirementContext {
+        packages,
+        installed_packages,
+        providers: PrefabProviders::new(packages),
+    };
+    let mut reports = Vec::new();
+    for (package, files) in packages {
+        for (asset_type, asset) in files.submarine_assets() {
+            reports.push(context.check_submarine(asset, Some(package), Some(asset_type)));
+        }
+    }
+    for path in sub_files {
+        match SubmarineAsset::load_from_path(path.to_string_lossy().into_owned()) {
+            Ok(asset) => reports.push(context.check_submarine(&asset, None, None)),
+            Err(e) => log::error!("Failed to load submarine {}: {}", path.display(), e),
+        }
+    }
+    reports
+}
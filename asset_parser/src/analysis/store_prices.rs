@@ -0,0 +1,283 @@
+use std::{collections::BTreeMap, fmt::Write, ops::RangeInclusive, sync::Arc};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{identifier::Identifier, prefabs::location_type::LocationType},
+};
+
+use super::{effective_location_types, variants::ResolvedItems};
+
+/// Ratio between the highest and lowest price of an item across its definitions that counts as a wild difference
+const PRICE_SPREAD_THRESHOLD: f32 = 2.0;
+
+/// An item in a store at locations of a type owned by a faction, either with the store's own price info or with the default one
+#[derive(Debug)]
+pub struct StockedItem {
+    /// `None` for the default price used by stores without their own price info
+    pub store: Option<Identifier>,
+    /// `None` when no location type has an outpost
+    pub location_type: Option<Identifier>,
+    /// `None` when the location type doesn't set a faction, reputation doesn't change prices then
+    pub faction: Option<String>,
+    pub item: Identifier,
+    pub package: Arc<AnyContentPackage>,
+    pub price: u32,
+    /// What players pay across the price modifier range of the location type and the reputation with the faction
+    pub buy_price: RangeInclusive<f32>,
+    /// What players get for selling the item across the same modifiers
+    pub sell_price: RangeInclusive<f32>,
+    pub sold: bool,
+    pub min_amount: u32,
+    pub max_amount: u32,
+    pub min_level_difficulty: u32,
+    pub requires_unlock: bool,
+    /// Minimum reputation per faction, sorted by faction
+    pub min_reputations: Vec<(String, f32)>,
+}
+
+/// Base prices of an item that differ wildly between the packages defining it
+#[derive(Debug)]
+pub struct PriceOverride {
+    pub item: Identifier,
+    pub prices: Vec<(Arc<AnyContentPackage>, u32)>,
+}
+
+impl PriceOverride {
+    /// Ratio between the highest and lowest price
+    pub fn spread(&self) -> f32 {
+        let min = self
+            .prices
+            .iter()
+            .map(|(_, v)| *v)
+            .min()
+            .unwrap_or_default();
+        let max = self
+            .prices
+            .iter()
+            .map(|(_, v)| *v)
+            .max()
+            .unwrap_or_default();
+        max as f32 / min.max(1) as f32
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StorePriceReport {
+    pub stock: Vec<StockedItem>,
+    pub price_overrides: Vec<PriceOverride>,
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+impl StorePriceReport {
+    /// One row per stocked item, location type and faction, items with wildly differing prices between packages list all of them
+    pub fn to_csv(&self) -> String {
+        let overrides = self
+            .price_overrides
+            .iter()
+            .map(|v| (&v.item, v))
+            .collect::<BTreeMap<_, _>>();
+        let mut s = String::from(
+            "store,location_type,faction,item,package,price,min_buy_price,max_buy_price,min_sell_price,max_sell_price,sold,min_amount,max_amount,min_level_difficulty,requires_unlock,min_reputations,override_prices\n",
+        );
+        for row in &self.stock {
+            let reputations = row
+                .min_reputations
+                .iter()
+                .map(|(faction, v)| format!("{}={}", faction, v))
+                .collect::<Vec<_>>()
+                .join(";");
+            let override_prices = overrides
+                .get(&row.item)
+                .map(|v| {
+                    v.prices
+                        .iter()
+                        .map(|(package, price)| format!("{}={}", package.package_id(), price))
+                        .collect::<Vec<_>>()
+                        .join(";")
+                })
+                .unwrap_or_default();
+            let _ = writeln!(
+                s,
+                "{},{},{},{},{},{},{:.0},{:.0},{:.0},{:.0},{},{},{},{},{},{},{}",
+                csv_field(row.store.as_ref().map_or("", |v| v.as_str())),
+                csv_field(row.location_type.as_ref().map_or("", |v| v.as_str())),
+                csv_field(row.faction.as_deref().unwrap_or_default()),
+                csv_field(row.item.as_str()),
+                csv_field(&row.package.package_id()),
+                row.price,
+                row.buy_price.start(),
+                row.buy_price.end(),
+                row.sell_price.start(),
+                row.sell_price.end(),
+                row.sold,
+                row.min_amount,
+                row.max_amount,
+                row.min_level_difficulty,
+                row.requires_unlock,
+                csv_field(&reputations),
+                csv_field(&override_prices)
+            );
+        }
+        s
+    }
+}
+
+/// Price multipliers of buying and selling at locations of a type owned by a faction
+struct PriceModifiers<'a> {
+    location_type: Option<&'a Identifier>,
+    faction: Option<&'a str>,
+    buy: RangeInclusive<f32>,
+    sell: RangeInclusive<f32>,
+}
+
+impl<'a> PriceModifiers<'a> {
+    /// One per location type with an outpost and faction its locations can have
+    fn new(location_types: impl Iterator<Item = &'a LocationType>) -> Vec<Self> {
+        let mut modifiers = Vec::new();
+        for location_type in location_types.filter(|v| v.has_outpost) {
+            let range = location_type.store_price_modifier_range as f32 / 100.0;
+            let mut factions = [&location_type.faction, &location_type.secondary_faction]
+                .into_iter()
+                .flatten()
+                .map(|v| Some(v.as_str()))
+                .collect::<Vec<_>>();
+            if factions.is_empty() {
+                factions.push(None);
+            }
+            for faction in factions {
+                //reputation moves prices up to the max reputation modifier either way
+                let reputation = if faction.is_some() {
+                    location_type.store_max_reputation_modifier
+                } else {
+                    0.0
+                };
+                let (min, max) = (
+                    (1.0 - range) * (1.0 - reputation),
+                    (1.0 + range) * (1.0 + reputation),
+                );
+                let sell_modifier = location_type.store_sell_price_modifier;
+                modifiers.push(Self {
+                    location_type: Some(&location_type.identifier),
+                    faction,
+                    buy: min..=max,
+                    sell: min * sell_modifier..=max * sell_modifier,
+                });
+            }
+        }
+        if modifiers.is_empty() {
+            modifiers.push(Self {
+                location_type: None,
+                faction: None,
+                buy: 1.0..=1.0,
+                sell: 1.0..=1.0,
+            });
+        }
+        modifiers
+    }
+
+    fn apply(range: &RangeInclusive<f32>, price: f32) -> RangeInclusive<f32> {
+        range.start() * price..=range.end() * price
+    }
+}
+
+fn sorted_reputations<'a>(
+    reputations: impl Iterator<Item = (&'a String, &'a f32)>,
+) -> Vec<(String, f32)> {
+    let mut reputations = reputations
+        .map(|(faction, v)| (faction.clone(), *v))
+        .collect::<Vec<_>>();
+    reputations.sort_by(|a, b| a.0.cmp(&b.0));
+    reputations
+}
+
+pub fn store_price_report(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    resolved: &ResolvedItems,
+) -> StorePriceReport {
+    let mut definitions: BTreeMap<&Identifier, Vec<(Arc<AnyContentPackage>, u32)>> =
+        BTreeMap::new();
+    for (package, files) in packages {
+        for item in files.items.iter().flat_map(|file| &file.items) {
+            if let Some(price) = &item.value.default_price {
+                definitions
+                    .entry(item.value.get_identifier())
+                    .or_default()
+                    .push((package.clone(), price.price));
+            }
+        }
+    }
+    let modifiers = PriceModifiers::new(effective_location_types(packages).into_values());
+
+    let mut report = StorePriceReport::default();
+    for (identifier, (package, item)) in resolved.items() {
+        if let Some(price) = &item.default_price {
+            let buy_price = price.price as f32 * price.buying_price_multiplier;
+            for modifiers in &modifiers {
+                report.stock.push(StockedItem {
+                    store: None,
+                    location_type: modifiers.location_type.cloned(),
+                    faction: modifiers.faction.map(str::to_owned),
+                    item: identifier.clone(),
+                    package: package.clone(),
+                    price: price.price,
+                    buy_price: PriceModifiers::apply(&modifiers.buy, buy_price),
+                    sell_price: PriceModifiers::apply(&modifiers.sell, price.price as f32),
+                    sold: price.can_be_bought,
+                    min_amount: price.min_available_amount,
+                    max_amount: price.max_available_amount,
+                    min_level_difficulty: price.min_level_difficulty,
+                    requires_unlock: price.requires_unlock,
+                    min_reputations: sorted_reputations(price.min_reputations.iter()),
+                });
+            }
+        }
+        let mut store_prices = item.store_prices.iter().collect::<Vec<_>>();
+        store_prices.sort_by(|a, b| a.0.cmp(b.0));
+        for (store, price) in store_prices {
+            let buy_price = price.price as f32 * price.buying_price_multiplier;
+            for modifiers in &modifiers {
+                report.stock.push(StockedItem {
+                    store: Some(store.clone()),
+                    location_type: modifiers.location_type.cloned(),
+                    faction: modifiers.faction.map(str::to_owned),
+                    item: identifier.clone(),
+                    package: package.clone(),
+                    price: price.price,
+                    buy_price: PriceModifiers::apply(&modifiers.buy, buy_price),
+                    sell_price: PriceModifiers::apply(&modifiers.sell, price.price as f32),
+                    sold: price.can_be_bought,
+                    min_amount: price.min_available_amount,
+                    max_amount: price.max_available_amount,
+                    min_level_difficulty: price.min_level_difficulty,
+                    requires_unlock: price.requires_unlock,
+                    min_reputations: sorted_reputations(price.min_reputations.iter()),
+                });
+            }
+        }
+    }
+    //items sold in specific stores come first, grouped by store
+    report.stock.sort_by(|a, b| {
+        b.store
+            .is_some()
+            .cmp(&a.store.is_some())
+            .then_with(|| a.store.cmp(&b.store))
+    });
+
+    report.price_overrides = definitions
+        .into_iter()
+        .filter(|(_, prices)| prices.len() > 1)
+        .map(|(identifier, prices)| PriceOverride {
+            item: identifier.clone(),
+            prices,
+        })
+        .filter(|v| v.spread() >= PRICE_SPREAD_THRESHOLD)
+        .collect();
+    report
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use crate::{
+    content_file::SubmarineAsset,
+    content_package::{AnyContentPackage, ContentFiles},
+};
+
+use super::SubmarineRef;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmarineConflictKind {
+    /// Different submarines using the same name
+    SameName,
+    /// Same name as a vanilla submarine
+    VanillaName,
+    /// The exact same submarine shipped by multiple packages
+    IdenticalCopy,
+}
+
+#[derive(Debug)]
+pub struct SubmarineConflict {
+    pub kind: SubmarineConflictKind,
+    pub name: String,
+    pub submarines: Vec<SubmarineRef>,
+}
+
+/// The game falls back to the file name when a submarine doesn't have a name
+pub fn submarine_name(asset: &SubmarineAsset) -> String {
+    asset.sub.submarine_info.name.clone().unwrap_or_else(|| {
+        Path::new(&asset.file_path)
+            .file_stem()
+            .map_or(asset.file_path.clone(), |v| {
+                v.to_string_lossy().into_owned()
+            })
+    })
+}
+
+fn distinct_package_count(submarines: &[SubmarineRef]) -> usize {
+    let mut packages: Vec<&Arc<AnyContentPackage>> = Vec::new();
+    for sub in submarines {
+        if !packages.iter().any(|p| Arc::ptr_eq(p, &sub.package)) {
+            packages.push(&sub.package);
+        }
+    }
+    packages.len()
+}
+
+pub fn find_submarine_conflicts(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> Vec<SubmarineConflict> {
+    let mut by_name: HashMap<String, (String, Vec<SubmarineRef>)> = HashMap::new();
+    let mut by_hash: HashMap<[u8; 16], (String, Vec<SubmarineRef>)> = HashMap::new();
+    for (package, files) in packages {
+        for (asset_type, asset) in files.submarine_assets() {
+            let name = submarine_name(asset);
+            let sub = SubmarineRef::new(package, asset_type, asset);
+            by_hash
+                .entry(asset.hash)
+                .or_insert_with(|| (name.clone(), Vec::new()))
+                .1
+                .push(sub.clone());
+            by_name
+                .entry(name.to_lowercase())
+                .or_insert_with(|| (name, Vec::new()))
+                .1
+                .push(sub);
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (name, submarines) in by_hash.into_values() {
+        if distinct_package_count(&submarines) > 1 {
+            conflicts.push(SubmarineConflict {
+                kind: SubmarineConflictKind::IdenticalCopy,
+                name,
+                submarines,
+            });
+        }
+    }
+    for (name, submarines) in by_name.into_values() {
+        if distinct_package_count(&submarines) < 2 {
+            continue;
+        }
+        //already reported as identical copies
+        if submarines.iter().all(|v| v.hash == submarines[0].hash) {
+            continue;
+        }
+        let kind = if submarines
+            .iter()
+            .any(|v| matches!(*v.package, AnyContentPackage::Core(_)))
+        {
+            SubmarineConflictKind::VanillaName
+        } else {
+            SubmarineConflictKind::SameName
+        };
+        conflicts.push(SubmarineConflict {
+            kind,
+            name,
+            submarines,
+        });
+    }
+    conflicts.sort_by(|a, b| a.name.cmp(&b.name));
+    conflicts
+}
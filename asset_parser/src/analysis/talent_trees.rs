@@ -0,0 +1,215 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::{Display, Write},
+    sync::Arc,
+};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{identifier::Identifier, prefabs::talent_tree_prefab::TalentTree},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtreeRelation {
+    Requires,
+    Blocks,
+}
+
+#[derive(Debug, Clone)]
+pub enum TalentTreeIssue {
+    MissingJob,
+    MissingTalent {
+        subtree: Identifier,
+        talent: Identifier,
+    },
+    /// A subtree requires or blocks a subtree the job's tree doesn't have
+    UnknownSubtree {
+        subtree: Identifier,
+        relation: SubtreeRelation,
+        other: Identifier,
+    },
+}
+
+/// Issue with the effective talent tree of a job
+#[derive(Debug, Clone)]
+pub struct TalentTreeProblem {
+    pub job: Identifier,
+    /// Package the effective tree comes from
+    pub package: Arc<AnyContentPackage>,
+    pub issue: TalentTreeIssue,
+}
+
+impl Display for TalentTreeProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Talent tree of {} ({}): ",
+            self.job,
+            self.package.package_id()
+        )?;
+        match &self.issue {
+            TalentTreeIssue::MissingJob => write!(f, "job is not defined"),
+            TalentTreeIssue::MissingTalent { subtree, talent } => {
+                write!(f, "subtree {} uses undefined talent {}", subtree, talent)
+            }
+            TalentTreeIssue::UnknownSubtree {
+                subtree,
+                relation,
+                other,
+            } => write!(
+                f,
+                "subtree {} {} {}, which is not a subtree of this job",
+                subtree,
+                match relation {
+                    SubtreeRelation::Requires => "requires",
+                    SubtreeRelation::Blocks => "blocks",
+                },
+                other
+            ),
+        }
+    }
+}
+
+/// Talent trees that end up loaded with the package defining them, the last package in load order wins like in game
+pub fn effective_talent_trees(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> BTreeMap<&Identifier, (&Arc<AnyContentPackage>, &TalentTree)> {
+    let mut trees = BTreeMap::new();
+    for (package, files) in packages {
+        for tree in files.talent_trees.iter().flat_map(|file| &file.trees) {
+            trees.insert(tree.value.get_identifier(), (package, &tree.value));
+        }
+    }
+    trees
+}
+
+fn defined_talents(packages: &[(Arc<AnyContentPackage>, ContentFiles)]) -> HashSet<&Identifier> {
+    packages
+        .iter()
+        .flat_map(|(_, files)| &files.talents)
+        .flat_map(|file| &file.items)
+        .map(|v| &v.value.identifier)
+        .collect()
+}
+
+pub fn check_talent_trees(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+) -> Vec<TalentTreeProblem> {
+    let talents = defined_talents(packages);
+    let jobs = packages
+        .iter()
+        .flat_map(|(_, files)| &files.jobs)
+        .flat_map(|file| &file.jobs)
+        .map(|v| &v.value.identifier)
+        .collect::<HashSet<_>>();
+
+    let mut problems = Vec::new();
+    for (job, (package, tree)) in effective_talent_trees(packages) {
+        let mut push = |issue| {
+            problems.push(TalentTreeProblem {
+                job: job.clone(),
+                package: package.clone(),
+                issue,
+            })
+        };
+        if !jobs.contains(job) {
+            push(TalentTreeIssue::MissingJob);
+        }
+        let subtrees = tree
+            .sub_trees
+            .iter()
+            .map(|v| &v.identifier)
+            .collect::<HashSet<_>>();
+        for subtree in &tree.sub_trees {
+            for talent in subtree
+                .talent_option_stages
+                .iter()
+                .flat_map(|v| &v.identifiers)
+            {
+                if !talents.contains(talent) {
+                    push(TalentTreeIssue::MissingTalent {
+                        subtree: subtree.identifier.clone(),
+                        talent: talent.clone(),
+                    });
+                }
+            }
+            for (relation, others) in [
+                (SubtreeRelation::Requires, &subtree.required_trees),
+                (SubtreeRelation::Blocks, &subtree.blocked_trees),
+            ] {
+                for other in others.iter().flatten() {
+                    if !subtrees.contains(other) {
+                        push(TalentTreeIssue::UnknownSubtree {
+                            subtree: subtree.identifier.clone(),
+                            relation,
+                            other: other.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    problems
+}
+
+/// The effective talent tree of every job as a table, undefined talents are marked with `(missing)`
+pub fn render_talent_trees(packages: &[(Arc<AnyContentPackage>, ContentFiles)]) -> String {
+    let talents = defined_talents(packages);
+    let mut s = String::new();
+    for (job, (package, tree)) in effective_talent_trees(packages) {
+        let _ = writeln!(s, "Job {} ({})", job, package.package_id());
+        let _ = writeln!(
+            s,
+            "{:<32} {:<15} {:>5} {:>5} {:>8}  Talents",
+            "Subtree", "Type", "Stage", "Picks", "Required"
+        );
+        for subtree in &tree.sub_trees {
+            let tree_type = format!("{:?}", subtree.tree_type);
+            for (i, stage) in subtree.talent_option_stages.iter().enumerate() {
+                let _ = writeln!(
+                    s,
+                    "{:<32} {:<15} {:>5} {:>5} {:>8}  {}",
+                    if i == 0 {
+                        subtree.identifier.as_str()
+                    } else {
+                        ""
+                    },
+                    if i == 0 { tree_type.as_str() } else { "" },
+                    i + 1,
+                    stage.max_chosen_talents,
+                    stage.required_talents,
+                    stage
+                        .identifiers
+                        .iter()
+                        .map(|v| if talents.contains(v) {
+                            v.to_string()
+                        } else {
+                            format!("{} (missing)", v)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+            for (relation, others) in [
+                ("requires", &subtree.required_trees),
+                ("blocks", &subtree.blocked_trees),
+            ] {
+                if let Some(others) = others {
+                    let _ = writeln!(
+                        s,
+                        "  {} {}: {}",
+                        subtree.identifier,
+                        relation,
+                        others
+                            .iter()
+                            .map(Identifier::as_str)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                }
+            }
+        }
+        s.push('\n');
+    }
+    s
+}
@@ -0,0 +1,417 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    sync::Arc,
+};
+
+use roxmltree::Document;
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{identifier::Identifier, prefabs::item_prefab::ItemPrefab, util::RawElement},
+};
+
+use super::effective_items;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantKind {
+    Item,
+    Character,
+}
+
+impl Display for VariantKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VariantKind::Item => write!(f, "item"),
+            VariantKind::Character => write!(f, "character"),
+        }
+    }
+}
+
+/// Parts of an item a variant takes from its base when it doesn't define them itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum InheritedAspect {
+    Name,
+    Tags,
+    Sprite,
+    Price,
+    Deconstruction,
+    Fabrication,
+    PreferredContainers,
+    LevelResource,
+    SuitableTreatments,
+    Body,
+    SwappableItem,
+}
+
+impl InheritedAspect {
+    pub fn is_defined_by(self, item: &ItemPrefab) -> bool {
+        match self {
+            InheritedAspect::Name => item.name.is_some(),
+            InheritedAspect::Tags => item.tags.is_some(),
+            InheritedAspect::Sprite => item.sprite.is_some(),
+            InheritedAspect::Price => item.default_price.is_some() || !item.store_prices.is_empty(),
+            InheritedAspect::Deconstruction => item.allow_deconstruct,
+            InheritedAspect::Fabrication => !item.fabrication_recipes.is_empty(),
+            InheritedAspect::PreferredContainers => !item.preferred_containers.is_empty(),
+            InheritedAspect::LevelResource => {
+                item.default_level_commonness.is_some()
+                    || !item.level_commonness.is_empty()
+                    || item.default_level_quantity.is_some()
+                    || !item.level_quantity.is_empty()
+            }
+            InheritedAspect::SuitableTreatments => !item.suitable_treatments.is_empty(),
+            InheritedAspect::Body => item.body.is_some() || item.static_body.is_some(),
+            InheritedAspect::SwappableItem => item.swappable_item.is_some(),
+        }
+    }
+}
+
+impl Display for InheritedAspect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                InheritedAspect::Name => "name",
+                InheritedAspect::Tags => "tags",
+                InheritedAspect::Sprite => "sprite",
+                InheritedAspect::Price => "price",
+                InheritedAspect::Deconstruction => "deconstruction",
+                InheritedAspect::Fabrication => "fabrication recipes",
+                InheritedAspect::PreferredContainers => "preferred containers",
+                InheritedAspect::LevelResource => "level resource",
+                InheritedAspect::SuitableTreatments => "suitable treatments",
+                InheritedAspect::Body => "body",
+                InheritedAspect::SwappableItem => "swappable item",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum VariantError {
+    MissingBase(Identifier),
+    /// The chain of bases loops back to the given base
+    Cycle(Identifier),
+    /// Merging the variant into its bases failed, the variant is used as defined
+    Merge(String),
+}
+
+/// An item with the chain of effective prefabs it inherits from
+#[derive(Debug)]
+pub struct ResolvedVariant<'a> {
+    /// The variant first, followed by its base, the base's base and so on
+    pub chain: Vec<(&'a Arc<AnyContentPackage>, &'a ItemPrefab)>,
+}
+
+impl<'a> ResolvedVariant<'a> {
+    /// Effective prefabs are the winning definitions, so overriding a base changes every variant of it
+    pub fn new(
+        items: &BTreeMap<&'a Identifier, (&'a Arc<AnyContentPackage>, &'a ItemPrefab)>,
+        variant: (&'a Arc<AnyContentPackage>, &'a ItemPrefab),
+    ) -> Result<Self, VariantError> {
+        let mut chain = vec![variant];
+        let mut visited = HashSet::from([variant.1.get_identifier()]);
+        let mut current = variant.1;
+        while let Some(base) = &current.variant_of {
+            let Some(&(package, prefab)) = items.get(base) else {
+                return Err(VariantError::MissingBase(base.clone()));
+            };
+            if !visited.insert(prefab.get_identifier()) {
+                return Err(VariantError::Cycle(base.clone()));
+            }
+            chain.push((package, prefab));
+            current = prefab;
+        }
+        Ok(Self { chain })
+    }
+
+    pub fn is_variant(&self) -> bool {
+        self.chain.len() > 1
+    }
+
+    /// The prefab the game loads for the variant, its element merged into the ones of its bases
+    pub fn merged(&self) -> Result<ItemPrefab, MergeError> {
+        let (_, root) = self.chain[self.chain.len() - 1];
+        let element = self.chain[..self.chain.len() - 1].iter().rev().try_fold(
+            RawElement::new(Document::parse(&root.source)?.root_element()),
+            |base, (_, v)| {
+                Ok::<_, MergeError>(
+                    RawElement::new(Document::parse(&v.source)?.root_element())
+                        .create_variant(&base),
+                )
+            },
+        )?;
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        element.write(&mut writer)?;
+        let xml = String::from_utf8(writer.into_inner())?;
+        Ok(ItemPrefab::new(Document::parse(&xml)?.root_element()))
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MergeError {
+    #[error("failed to parse utf8")]
+    Utf8(std::string::FromUtf8Error),
+    #[error("failed to parse xml")]
+    Xml(roxmltree::Error),
+    #[error("failed to write xml")]
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for MergeError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<std::string::FromUtf8Error> for MergeError {
+    fn from(value: std::string::FromUtf8Error) -> Self {
+        Self::Utf8(value)
+    }
+}
+
+impl From<roxmltree::Error> for MergeError {
+    fn from(value: roxmltree::Error) -> Self {
+        Self::Xml(value)
+    }
+}
+
+/// Effective item prefabs with every variant replaced by the prefab merged from its base
+#[derive(Debug)]
+pub struct ResolvedItems<'a> {
+    effective: BTreeMap<&'a Identifier, (&'a Arc<AnyContentPackage>, &'a ItemPrefab)>,
+    merged: HashMap<&'a Identifier, ItemPrefab>,
+    /// Variants that failed to merge, they are used as defined
+    pub issues: Vec<VariantIssue>,
+}
+
+impl<'a> ResolvedItems<'a> {
+    /// Merges every variant once, the analyses that need the merged prefabs share the result
+    pub fn new(packages: &'a [(Arc<AnyContentPackage>, ContentFiles)]) -> Self {
+        let effective = effective_items(packages);
+        let mut merged = HashMap::new();
+        let mut issues = Vec::new();
+        for (identifier, &variant) in &effective {
+            if variant.1.variant_of.is_none() {
+                continue;
+            }
+            //variants without a base are used as defined, check_variants reports them
+            let Ok(resolved) = ResolvedVariant::new(&effective, variant) else {
+                continue;
+            };
+            match resolved.merged() {
+                Ok(v) => {
+                    merged.insert(*identifier, v);
+                }
+                Err(error) => issues.push(VariantIssue {
+                    kind: VariantKind::Item,
+                    variant: (*identifier).clone(),
+                    package: variant.0.clone(),
+                    error: VariantError::Merge(error.to_string()),
+                }),
+            }
+        }
+        Self {
+            effective,
+            merged,
+            issues,
+        }
+    }
+
+    /// Like [`effective_items`], with the merged prefab of each variant
+    pub fn items(&self) -> BTreeMap<&Identifier, (&Arc<AnyContentPackage>, &ItemPrefab)> {
+        self.effective
+            .iter()
+            .map(|(identifier, &(package, item))| {
+                (
+                    *identifier,
+                    (package, self.merged.get(identifier).unwrap_or(item)),
+                )
+            })
+            .collect()
+    }
+}
+
+/// A variant that can't be resolved
+#[derive(Debug, Clone)]
+pub struct VariantIssue {
+    pub kind: VariantKind,
+    pub variant: Identifier,
+    pub package: Arc<AnyContentPackage>,
+    pub error: VariantError,
+}
+
+impl Display for VariantIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.error {
+            VariantError::MissingBase(base) => write!(
+                f,
+                "{} {} of {} is a variant of {}, which no enabled package defines",
+                self.kind,
+                self.variant,
+                self.package.package_id(),
+                base
+            ),
+            VariantError::Cycle(base) => write!(
+                f,
+                "{} {} of {} has a variant chain looping back to {}",
+                self.kind,
+                self.variant,
+                self.package.package_id(),
+                base
+            ),
+            VariantError::Merge(error) => write!(
+                f,
+                "{} {} of {} couldn't be merged with its bases: {}",
+                self.kind,
+                self.variant,
+                self.package.package_id(),
+                error
+            ),
+        }
+    }
+}
+
+/// A variant whose base is overridden by a package other than the one defining the variant
+#[derive(Debug, Clone)]
+pub struct AffectedVariant {
+    pub variant: Identifier,
+    pub package: Arc<AnyContentPackage>,
+    pub base: Identifier,
+    /// Package the winning definition of the base comes from
+    pub base_package: Arc<AnyContentPackage>,
+    /// Packages whose definitions of the base got overridden
+    pub overridden: Vec<Arc<AnyContentPackage>>,
+    /// Aspects the variant takes from this base or the bases above it
+    pub inherited: Vec<InheritedAspect>,
+}
+
+impl Display for AffectedVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "item {} of {} is a variant of {}, which {} overrides (replacing {:?})",
+            self.variant,
+            self.package.package_id(),
+            self.base,
+            self.base_package.package_id(),
+            self.overridden
+                .iter()
+                .map(|v| v.package_id())
+                .collect::<Vec<_>>()
+        )?;
+        if !self.inherited.is_empty() {
+            write!(
+                f,
+                ", inherited {}",
+                self.inherited
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VariantReport {
+    pub issues: Vec<VariantIssue>,
+    pub affected: Vec<AffectedVariant>,
+}
+
+pub fn check_variants(
+    packages: &[(Arc<AnyContentPackage>, ContentFiles)],
+    resolved: &ResolvedItems,
+) -> VariantReport {
+    let items = effective_items(packages);
+    let mut definitions: HashMap<&Identifier, Vec<&Arc<AnyContentPackage>>> = HashMap::new();
+    for (package, files) in packages {
+        for item in files.items.iter().flat_map(|file| &file.items) {
+            definitions
+                .entry(item.value.get_identifier())
+                .or_default()
+                .push(package);
+        }
+    }
+
+    let mut report = VariantReport {
+        issues: resolved.issues.clone(),
+        ..Default::default()
+    };
+    for (identifier, &(package, item)) in &items {
+        if item.variant_of.is_none() {
+            continue;
+        }
+        let variant = match ResolvedVariant::new(&items, (package, item)) {
+            Ok(v) => v,
+            Err(error) => {
+                report.issues.push(VariantIssue {
+                    kind: VariantKind::Item,
+                    variant: (*identifier).clone(),
+                    package: package.clone(),
+                    error,
+                });
+                continue;
+            }
+        };
+        for (i, (base_package, base)) in variant.chain.iter().enumerate().skip(1) {
+            let Some(defined_by) = definitions.get(base.get_identifier()) else {
+                continue;
+            };
+            if defined_by.len() < 2 || Arc::ptr_eq(base_package, package) {
+                continue;
+            }
+            report.affected.push(AffectedVariant {
+                variant: (*identifier).clone(),
+                package: package.clone(),
+                base: base.get_identifier().clone(),
+                base_package: (*base_package).clone(),
+                overridden: defined_by[..defined_by.len() - 1]
+                    .iter()
+                    .map(|v| (*v).clone())
+                    .collect(),
+                inherited: InheritedAspect::iter()
+                    .filter(|aspect| {
+                        !variant.chain[..i]
+                            .iter()
+                            .any(|(_, v)| aspect.is_defined_by(v))
+                            && variant.chain[i..]
+                                .iter()
+                                .any(|(_, v)| aspect.is_defined_by(v))
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    //characters have no conflict detection, so only check that their bases exist
+    let species = packages
+        .iter()
+        .flat_map(|(_, files)| &files.characters)
+        .filter_map(|file| file.character.species_name.as_ref())
+        .collect::<HashSet<_>>();
+    for (package, files) in packages {
+        for file in &files.characters {
+            let character = &file.character;
+            if let Some(base) = &character.variant_of
+                && !species.contains(base)
+            {
+                report.issues.push(VariantIssue {
+                    kind: VariantKind::Character,
+                    variant: character
+                        .species_name
+                        .clone()
+                        .unwrap_or_else(|| Identifier::from(&file.file_path)),
+                    package: package.clone(),
+                    error: VariantError::MissingBase(base.clone()),
+                });
+            }
+        }
+    }
+    report
+}
@@ -1,4 +1,4 @@
-use std::{io::Read, ops::Deref};
+use std::{fmt::Display, io::Read, ops::Deref};
 
 use flate2::read::GzDecoder;
 use md5::{Digest, Md5};
@@ -45,16 +45,12 @@ pub struct SubmarineAsset {
 
 impl SubmarineAsset {
     pub fn load_from_path(file_path: String) -> Result<Self, SubAssetLoaderError> {
-        let s = std::fs::read(&file_path).unwrap();
+        let s = std::fs::read(&file_path)?;
         Self::load(&s, file_path)
     }
 
     pub fn load(bytes: &[u8], file_path: String) -> Result<Self, SubAssetLoaderError> {
-        let mut decoder = GzDecoder::new(bytes);
-        let mut buf = Vec::new();
-        decoder.read_to_end(&mut buf)?;
-
-        let raw = String::from_utf8(buf)?;
+        let raw = Self::decompress(bytes)?;
 
         let hash = {
             let mut hasher = Md5::new();
@@ -70,6 +66,43 @@ impl SubmarineAsset {
             file_path,
         })
     }
+
+    /// Raw xml of a gzipped submarine file
+    pub fn decompress(bytes: &[u8]) -> Result<String, SubAssetLoaderError> {
+        let mut decoder = GzDecoder::new(bytes);
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+
+        Ok(String::from_utf8(buf)?)
+    }
+}
+
+/// Content type a submarine asset was loaded as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubmarineAssetType {
+    Submarine,
+    Outpost,
+    OutpostModule,
+    Wreck,
+    BeaconStation,
+    EnemySubmarine,
+}
+
+impl Display for SubmarineAssetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Submarine => "Submarine",
+                Self::Outpost => "Outpost",
+                Self::OutpostModule => "Outpost Module",
+                Self::Wreck => "Wreck",
+                Self::BeaconStation => "Beacon Station",
+                Self::EnemySubmarine => "Enemy Submarine",
+            }
+        )
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -1,4 +1,5 @@
 use std::{
+    fmt::Display,
     io::Cursor,
     marker::PhantomData,
     path::{Path, PathBuf},
@@ -8,9 +9,12 @@ use regex::RegexBuilder;
 use roxmltree::{Document, Node};
 
 use crate::{
-    content_file::{ContentFile, SubmarineAsset},
+    content_file::{ContentFile, SubmarineAsset, SubmarineAssetType},
     shared::{
-        content_files::prelude::*, date_time::SerializableDateTime, util::NodeExp, version::Version,
+        content_files::prelude::*,
+        date_time::SerializableDateTime,
+        util::{NodeExp, RawElement},
+        version::Version,
     },
 };
 
@@ -76,6 +80,17 @@ pub struct ContentFilePaths {
     pub skill_settings: Vec<String>,
     pub faction_prefabs: Vec<String>,
     pub tutorial_prefabs: Vec<String>,
+    /// Ignored by the game, kept so they can be written back
+    pub other: Vec<String>,
+}
+
+/// Child of the filelist.xml root element
+#[derive(Debug, Clone)]
+pub enum FileListElement {
+    /// Content file listed in `ContentFilePaths`, dropped on save when its path was removed from there
+    Content(RawElement),
+    /// Element this tool doesn't know about, written back as is
+    Unknown(RawElement),
 }
 
 #[derive(Debug, Default)]
@@ -125,6 +140,54 @@ pub struct ContentFiles {
     pub tutorial_prefabs: Vec<ContentFile<TutorialsFile>>,
 }
 
+impl ContentFiles {
+    /// All submarine assets of every submarine content type
+    pub fn submarine_assets(&self) -> impl Iterator<Item = (SubmarineAssetType, &SubmarineAsset)> {
+        [
+            (SubmarineAssetType::Submarine, &self.submarines),
+            (SubmarineAssetType::Outpost, &self.outposts),
+            (SubmarineAssetType::OutpostModule, &self.outpost_modules),
+            (SubmarineAssetType::Wreck, &self.wrecks),
+            (SubmarineAssetType::BeaconStation, &self.beacon_stations),
+            (SubmarineAssetType::EnemySubmarine, &self.enemy_submarines),
+        ]
+        .into_iter()
+        .flat_map(|(asset_type, assets)| assets.iter().map(move |asset| (asset_type, asset)))
+    }
+}
+
+/// Code shipped alongside a package's content, its runtime behavior is not analyzed
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PackageCode {
+    pub csharp: bool,
+    /// LuaCs style `Lua` folder
+    pub lua: bool,
+}
+
+impl PackageCode {
+    pub fn detect(mod_path: &Path) -> Self {
+        Self {
+            csharp: mod_path.join("CSharp").exists(),
+            lua: mod_path.join("Lua").exists(),
+        }
+    }
+
+    pub fn has_code(&self) -> bool {
+        self.csharp || self.lua
+    }
+}
+
+impl Display for PackageCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.csharp, self.lua) {
+            (true, true) => write!(f, "C#, Lua"),
+            (true, false) => write!(f, "C#"),
+            (false, true) => write!(f, "Lua"),
+            (false, false) => write!(f, "None"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum AnyContentPackage {
     Core(ContentPackage<Core>),
@@ -139,6 +202,13 @@ impl AnyContentPackage {
         }
     }
 
+    pub fn alt_names(&self) -> &Option<Vec<String>> {
+        match self {
+            AnyContentPackage::Core(content_package) => &content_package.alt_names,
+            AnyContentPackage::Regular(content_package) => &content_package.alt_names,
+        }
+    }
+
     pub fn steam_workshop_id(&self) -> &Option<u64> {
         match self {
             AnyContentPackage::Core(content_package) => &content_package.steam_workshop_id,
@@ -158,6 +228,20 @@ impl AnyContentPackage {
             .unwrap_or_else(|| self.name().clone().unwrap())
     }
 
+    pub fn game_version(&self) -> &Option<Version> {
+        match self {
+            AnyContentPackage::Core(content_package) => &content_package.game_version,
+            AnyContentPackage::Regular(content_package) => &content_package.game_version,
+        }
+    }
+
+    pub fn code(&self) -> PackageCode {
+        match self {
+            AnyContentPackage::Core(content_package) => content_package.code,
+            AnyContentPackage::Regular(content_package) => content_package.code,
+        }
+    }
+
     pub fn expected_hash(&self) -> &Option<String> {
         match self {
             AnyContentPackage::Core(content_package) => &content_package.expected_hash,
@@ -180,6 +264,16 @@ pub struct ContentPackage<T: ContentPackageType> {
 
     pub file_paths: ContentFilePaths,
 
+    /// Attributes of the root element in the order they were read
+    pub attributes: Vec<(String, String)>,
+    /// Children of the root element in the order they were read
+    pub elements: Vec<FileListElement>,
+    /// Parsed attributes as they were when loaded, the raw values are written back as long as these don't change
+    loaded_attributes: Vec<(&'static str, Option<String>)>,
+
+    /// Not part of filelist.xml, detected from the package folder by the loader
+    pub code: PackageCode,
+
     _phantom: PhantomData<T>,
 }
 
@@ -226,14 +320,28 @@ impl<T: ContentPackageType> ContentPackage<T> {
             .unwrap(); //TODO: error handling;
         assert_eq!(is_core_package, T::IS_CORE);
 
+        let attributes = root
+            .attributes()
+            .map(|a| (a.name().to_owned(), a.value().to_owned()))
+            .collect::<Vec<_>>();
+
         let mut file_paths = ContentFilePaths::default();
+        let mut elements = Vec::new();
 
         for element in root.children().filter(Node::is_element) {
             let elem_name = element.tag_name().name();
-            let file_path = element
+            let Some(file_path) = element
                 .attribute_ignore_ascii_case("file")
-                .unwrap()
-                .to_owned();
+                .map(std::borrow::ToOwned::to_owned)
+            else {
+                log::warn!(
+                    "Element {} has no file attribute, it will be kept as is",
+                    elem_name
+                );
+                elements.push(FileListElement::Unknown(RawElement::new(element)));
+                continue;
+            };
+            let mut known = true;
             match elem_name {
                 "Item" => {
                     file_paths.items.push(file_path);
@@ -366,14 +474,25 @@ impl<T: ContentPackageType> ContentPackage<T> {
                 }
                 "Other" => {
                     //Ignored by the game
+                    file_paths.other.push(file_path);
                 }
                 _ => {
-                    log::error!("TODO: {}", file_path)
+                    log::warn!(
+                        "Unknown content type {} ({}), it will be kept as is",
+                        elem_name,
+                        file_path
+                    );
+                    known = false;
                 }
             }
+            let element = RawElement::new(element);
+            elements.push(match known {
+                true => FileListElement::Content(element),
+                false => FileListElement::Unknown(element),
+            });
         }
 
-        Ok(ContentPackage {
+        let mut package = ContentPackage {
             name,
             alt_names,
             steam_workshop_id,
@@ -383,7 +502,46 @@ impl<T: ContentPackageType> ContentPackage<T> {
             expected_hash,
             _phantom: PhantomData,
             file_paths,
-        })
+            attributes,
+            elements,
+            loaded_attributes: Vec::new(),
+            code: PackageCode::default(),
+        };
+        package.loaded_attributes = package.known_attributes().into();
+        Ok(package)
+    }
+
+    /// Parsed root attributes as they are written, in the order new ones are added
+    fn known_attributes(&self) -> [(&'static str, Option<String>); 8] {
+        [
+            ("name", self.name.clone()),
+            (
+                "corepackage",
+                Some(
+                    match T::IS_CORE {
+                        true => "true",
+                        false => "false",
+                    }
+                    .to_owned(),
+                ),
+            ),
+            (
+                "gameversion",
+                self.game_version.as_ref().map(|v| v.to_string()),
+            ),
+            ("altnames", self.alt_names.as_ref().map(|v| v.join(","))),
+            (
+                "steamworkshopid",
+                self.steam_workshop_id.map(|v| v.to_string()),
+            ),
+            ("modversion", self.mod_version.clone()),
+            //Should never see this during development or in workshop as this is added by barotrauma when the mod is installed in a different folder
+            (
+                "installtime",
+                self.install_time.as_ref().map(|v| v.to_string()),
+            ),
+            ("expectedhash", self.expected_hash.clone()),
+        ]
     }
 
     pub fn load_file_list(
@@ -463,35 +621,42 @@ impl<T: ContentPackageType> ContentPackage<T> {
     pub fn save(&self, path: &Path) -> Result<(), std::io::Error> {
         let mut writer = quick_xml::Writer::new(Cursor::new(Vec::new()));
 
-        let mut root = writer.create_element("contentpackage");
-        if let Some(attr) = &self.name {
-            root = root.with_attribute(("name", attr.as_str()));
-        }
-        root = root.with_attribute(("corepackage", match T::IS_CORE {
-            true => "true",
-            false => "false",
-        }));
-        if let Some(attr) = &self.game_version {
-            root = root.with_attribute(("gameversion", attr.to_string().as_str()));
-        }
-        if let Some(attr) = &self.alt_names {
-            root = root.with_attribute(("altnames", attr.join(",").as_str()));
-        }
-        if let Some(attr) = &self.steam_workshop_id {
-            root = root.with_attribute(("steamworkshopid", attr.to_string().as_str()));
+        let known_attributes = self.known_attributes();
+        let loaded = |name: &str| {
+            self.loaded_attributes
+                .iter()
+                .find(|(v, _)| *v == name)
+                .and_then(|(_, v)| v.as_ref())
+        };
+        let mut attributes = Vec::new();
+        for (name, value) in &self.attributes {
+            match known_attributes
+                .iter()
+                .find(|(known, _)| name.eq_ignore_ascii_case(known))
+            {
+                Some((known, current)) if current.as_ref() != loaded(known) => {
+                    if let Some(current) = current {
+                        attributes.push((name.as_str(), current.as_str()));
+                    }
+                }
+                _ => attributes.push((name.as_str(), value.as_str())),
+            }
         }
-        if let Some(attr) = &self.mod_version {
-            root = root.with_attribute(("modversion", attr.as_str()));
+        for (name, current) in &known_attributes {
+            if let Some(current) = current
+                && Some(current) != loaded(name)
+                && !self
+                    .attributes
+                    .iter()
+                    .any(|(v, _)| v.eq_ignore_ascii_case(name))
+            {
+                attributes.push((name, current.as_str()));
+            }
         }
 
-        //TODO: Should never see this during development or in workshop as this is added by barotrauma when the mod is installed in a different folder, but probably nice to have anyway
-        //if let Some(attr) = &self.install_time {
-        //    root = root.with_attribute(("installtime", attr.as_str()));
-        //}
-
-        if let Some(attr) = &self.expected_hash {
-            root = root.with_attribute(("expectedhash", attr.as_str()));
-        }
+        let root = writer
+            .create_element("contentpackage")
+            .with_attributes(attributes);
 
         root.write_inner_content(|writer| {
             macro_rules! inner_content {
@@ -499,16 +664,15 @@ impl<T: ContentPackageType> ContentPackage<T> {
                     $from: expr,
                     $($elem_name: literal, $field: ident);*
                 ) => {
-                    $(
-                        for path in &$from.$field {
-                            let el = writer.create_element($elem_name).with_attribute(("file", path.as_str()));
-                            el.write_empty()?;
-                        }
-                    )*
+                    vec![
+                        $(
+                            ($elem_name, $from.$field.iter().collect::<Vec<_>>()),
+                        )*
+                    ]
                 };
             }
 
-            inner_content!(
+            let mut remaining: Vec<(&str, Vec<&String>)> = inner_content!(
                 &self.file_paths,
 
                 "Item", items;
@@ -553,9 +717,47 @@ impl<T: ContentPackageType> ContentPackage<T> {
                 "Orders", order_prefabs;
                 "SkillSettings", skill_settings;
                 "Factions", faction_prefabs;
-                "Tutorials", tutorial_prefabs
+                "Tutorials", tutorial_prefabs;
+                "Other", other
             );
 
+            for element in &self.elements {
+                let element = match element {
+                    FileListElement::Unknown(element) => element,
+                    FileListElement::Content(element) => {
+                        //MapCreature is read as BallastFlora
+                        let content_type = match element.name.as_str() {
+                            "MapCreature" => "BallastFlora",
+                            name => name,
+                        };
+                        let Some((_, paths)) =
+                            remaining.iter_mut().find(|(v, _)| *v == content_type)
+                        else {
+                            continue;
+                        };
+                        let Some(i) = element
+                            .attribute("file")
+                            .and_then(|file| paths.iter().position(|v| *v == file))
+                        else {
+                            continue;
+                        };
+                        paths.remove(i);
+                        element
+                    }
+                };
+                element.write(writer)?;
+            }
+
+            //paths added since loading go after the ones that were read
+            for (elem_name, paths) in remaining {
+                for path in paths {
+                    writer
+                        .create_element(elem_name)
+                        .with_attribute(("file", path.as_str()))
+                        .write_empty()?;
+                }
+            }
+
             Ok(())
         })?;
 
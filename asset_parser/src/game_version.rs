@@ -0,0 +1,61 @@
+use std::{path::Path, sync::Arc};
+
+use roxmltree::Document;
+
+use crate::{
+    content_package::AnyContentPackage,
+    shared::{util::NodeExp, version::Version},
+};
+
+const VANILLA_FILELIST_PATH: &str = "Content/ContentPackages/Vanilla.xml";
+const DEPS_FILE_PATH: &str = "Barotrauma.deps.json";
+
+/// Detects the version of the game installed in `game_path`
+///
+/// The vanilla content package is checked first, `Barotrauma.deps.json` is used as a fallback
+pub fn detect_game_version(game_path: &Path) -> Option<Version> {
+    version_from_vanilla_filelist(game_path).or_else(|| version_from_deps_file(game_path))
+}
+
+fn version_from_vanilla_filelist(game_path: &Path) -> Option<Version> {
+    let s = std::fs::read_to_string(game_path.join(VANILLA_FILELIST_PATH)).ok()?;
+    let document = Document::parse(&s).ok()?;
+    document
+        .root_element()
+        .attribute_ignore_ascii_case("gameversion")
+        .and_then(|v| v.parse::<Version>().ok())
+}
+
+fn version_from_deps_file(game_path: &Path) -> Option<Version> {
+    let s = std::fs::read_to_string(game_path.join(DEPS_FILE_PATH)).ok()?;
+    //library entries look like "Barotrauma/1.7.7.0": { ... }
+    let start = s.find("\"Barotrauma/")? + "\"Barotrauma/".len();
+    let end = start + s[start..].find('"')?;
+    s[start..end].parse::<Version>().ok()
+}
+
+#[derive(Debug)]
+pub struct OutdatedPackage {
+    pub package: Arc<AnyContentPackage>,
+    pub game_version: Version,
+}
+
+/// Packages which were made for an older major or minor version of the game than `game_version`
+pub fn find_outdated_packages<'a>(
+    game_version: &Version,
+    packages: impl IntoIterator<Item = &'a Arc<AnyContentPackage>>,
+) -> Vec<OutdatedPackage> {
+    packages
+        .into_iter()
+        .filter_map(|package| {
+            package
+                .game_version()
+                .as_ref()
+                .filter(|v| v.is_older_major_minor(game_version))
+                .map(|v| OutdatedPackage {
+                    package: package.clone(),
+                    game_version: v.clone(),
+                })
+        })
+        .collect()
+}
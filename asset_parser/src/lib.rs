@@ -16,8 +16,11 @@ along with this program; if not, write to the Free Software
 Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 */
 
+pub mod analysis;
 pub mod content_file;
 pub mod content_package;
+pub mod game_version;
 pub mod loading;
 pub mod player_config;
 pub mod shared;
+pub mod submarine;
@@ -15,10 +15,29 @@ use std::{
 };
 
 use crate::{
+    analysis::{
+        SubmarineRef,
+        conversations::{ConversationReport, check_conversations},
+        crafting_exploits::{CraftingExploits, find_crafting_exploits},
+        dangling_references::{PackageReferences, find_dangling_references},
+        events::{EventIssue, check_events},
+        localization::{PackageLocalization, localization_coverage},
+        missing_prefabs::{MissingPrefabs, find_missing_prefabs},
+        obtainability::{ObtainabilityReport, find_item_sources},
+        required_packages::{RequiredPackagesReport, RequirementStatus, check_required_packages},
+        store_prices::{StorePriceReport, store_price_report},
+        submarine_conflicts::{SubmarineConflict, SubmarineConflictKind, find_submarine_conflicts},
+        talent_trees::{TalentTreeProblem, check_talent_trees},
+        variants::{ResolvedItems, VariantReport, check_variants},
+    },
     content_package::{
-        AnyContentPackage, ContentFilePaths, ContentFiles, ContentPackage, Core, Regular,
+        AnyContentPackage, ContentFilePaths, ContentFiles, ContentPackage, Core, PackageCode,
+        Regular,
     },
+    game_version::{OutdatedPackage, detect_game_version, find_outdated_packages},
     player_config::PlayerConfigFile,
+    shared::{identifier::Identifier, util::prefab_key, version::Version},
+    submarine::integrity::{IntegrityIssue, check_integrity},
 };
 
 macro_rules! detect_conflict {
@@ -26,7 +45,7 @@ macro_rules! detect_conflict {
         for item_file in &$content_file {
             for item in &item_file.$overridable_field {
                 let identifier = &item.value.get_identifier();
-                match $id_map.entry(identifier.to_string()) {
+                match $id_map.entry((*identifier).clone()) {
                     std::collections::hash_map::Entry::Occupied(mut occupied_entry) => {
                         if occupied_entry.get().was_overriden {
                             log::error!("[{}] id {} is already loaded!", $item_name, identifier);
@@ -78,7 +97,7 @@ macro_rules! detect_conflict_loop {
         let mut $conflicts_struct_name = Conflicts::default();
         $(
             paste! {
-                let mut [<loaded_ $content_file $overridable_field _id>]: HashMap<String, IdCheck> = HashMap::new();
+                let mut [<loaded_ $content_file $overridable_field _id>]: HashMap<Identifier, IdCheck> = HashMap::new();
             }
         )*
         for (package, content_files) in &$loaded_content_files {
@@ -93,6 +112,31 @@ macro_rules! detect_conflict_loop {
                 }
             )*
         }
+        log::info!("------Key collisions------");
+        $(
+            paste! {
+                let mut keys: HashMap<u32, Vec<(&Identifier, &IdCheck)>> = HashMap::new();
+                for (id, entry) in &[<loaded_ $content_file $overridable_field _id>] {
+                    keys.entry(prefab_key(id)).or_default().push((id, entry));
+                }
+                for (key, mut ids) in keys {
+                    if ids.len() > 1 {
+                        ids.sort_by(|a, b| a.0.cmp(b.0));
+                        log::error!(
+                            "{}: key {:#010x} is shared by: {:?}",
+                            $item_name,
+                            key,
+                            ids.iter().map(|(id, entry)| (id.to_string(), entry.added_by.iter().map(|v| v.package_id()).collect::<Vec<_>>())).collect::<Vec<_>>()
+                        );
+                        $conflicts_struct_name.key_collisions.push(KeyCollision {
+                            prefab_type: $item_name,
+                            key,
+                            identifiers: ids.into_iter().map(|(id, entry)| (id.clone(), entry.added_by.clone())).collect(),
+                        });
+                    }
+                }
+            }
+        )*
         log::info!("------Conflicts------");
         $(
             paste! {
@@ -110,6 +154,7 @@ macro_rules! detect_conflict_loop {
 pub fn load(
     game_path: PathBuf,
     config_player_path: PathBuf,
+    sub_files: Vec<PathBuf>,
 ) -> impl Stream<Item = Result<Progress, ()>> {
     try_channel(1, move |mut output| async move {
         if !game_path.exists() {
@@ -226,15 +271,16 @@ pub fn load(
                     max: num_mods,
                 })
                 .await;
-            let package =
+            let mut package =
                 ContentPackage::<Regular>::load(&std::fs::read_to_string(&v.path).unwrap())
                     .unwrap();
-            if Path::new(&v.path).parent().unwrap().join("CSharp").exists() {
+            package.code = PackageCode::detect(Path::new(&v.path).parent().unwrap());
+            if package.code.has_code() {
                 warn!(
-                    "C# mod detected: {}, C# mods are not checked by the conflict detector!",
-                    package.name.as_ref().unwrap_or(&v.path)
+                    "Mod with code detected: {} ({}), its XML content is checked but runtime behavior is not analyzed!",
+                    package.name.as_ref().unwrap_or(&v.path),
+                    package.code
                 );
-                continue;
             }
             info!(
                 "Parsing {}...",
@@ -255,6 +301,14 @@ pub fn load(
             loaded_content_files.push((Arc::new(AnyContentPackage::Regular(package)), files));
         }
 
+        let game_version = detect_game_version(&game_path);
+        match &game_version {
+            Some(v) => info!("Detected game version {}", v),
+            None => warn!(
+                "Failed to detect the game version, mods will not be checked for compatibility"
+            ),
+        }
+
         let _ = output.send(Progress::LoadingConflicts).await;
 
         info!("Done parsing, starting to detect conflicts...");
@@ -312,6 +366,248 @@ pub fn load(
             "Faction Prefabs",faction_prefabs,faction_prefabs,faction_prefabs_faction_prefabs;
             "Tutorial Prefabs",tutorial_prefabs,tutorial_prefabs,tutorial_prefabs_tutorial_prefabs
         );
+
+        if let Some(game_version) = &game_version {
+            conflicts.outdated_packages = find_outdated_packages(
+                game_version,
+                loaded_content_files.iter().map(|(package, _)| package),
+            );
+            for outdated in &conflicts.outdated_packages {
+                warn!(
+                    "{} was made for game version {}, installed game version is {}",
+                    outdated.package.package_id(),
+                    outdated.game_version,
+                    game_version
+                );
+            }
+        }
+        conflicts.game_version = game_version;
+        conflicts.packages_with_code = loaded_content_files
+            .iter()
+            .map(|(package, _)| package)
+            .filter(|package| package.code().has_code())
+            .cloned()
+            .collect();
+
+        conflicts.submarine_conflicts = find_submarine_conflicts(&loaded_content_files);
+        for conflict in &conflicts.submarine_conflicts {
+            let defined_by = conflict
+                .submarines
+                .iter()
+                .map(|v| {
+                    format!(
+                        "{} ({}, {})",
+                        v.package.package_id(),
+                        v.asset_type,
+                        v.file_path
+                    )
+                })
+                .collect::<Vec<_>>();
+            match conflict.kind {
+                SubmarineConflictKind::SameName => log::error!(
+                    "Submarine name {} is used by: {:?}",
+                    conflict.name,
+                    defined_by
+                ),
+                SubmarineConflictKind::VanillaName => log::error!(
+                    "Submarine name {} clashes with a vanilla submarine: {:?}",
+                    conflict.name,
+                    defined_by
+                ),
+                SubmarineConflictKind::IdenticalCopy => warn!(
+                    "Submarine {} is shipped unchanged by multiple packages: {:?}",
+                    conflict.name, defined_by
+                ),
+            }
+        }
+
+        conflicts.missing_prefabs = find_missing_prefabs(&loaded_content_files);
+        for missing in &conflicts.missing_prefabs {
+            log::error!(
+                "Submarine {} ({}) of {} uses undefined prefabs, items: {:?}, structures: {:?}",
+                missing.submarine.file_path,
+                missing.submarine.asset_type,
+                missing.submarine.package.package_id(),
+                missing
+                    .items
+                    .iter()
+                    .map(|(id, count)| format!("{} x{}", id, count))
+                    .collect::<Vec<_>>(),
+                missing
+                    .structures
+                    .iter()
+                    .map(|(id, count)| format!("{} x{}", id, count))
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        conflicts.required_packages =
+            check_required_packages(&loaded_content_files, &installed_packages, &sub_files);
+        for report in conflicts
+            .required_packages
+            .iter()
+            .filter(|v| v.has_issues())
+        {
+            let submarine = match &report.package {
+                Some(package) => format!("{} of {}", report.file_path, package.package_id()),
+                None => report.file_path.clone(),
+            };
+            for (name, status) in &report.requirements {
+                match status {
+                    RequirementStatus::Enabled(_) => {}
+                    RequirementStatus::EnabledUnderDifferentName(package) => warn!(
+                        "Submarine {} requires {}, which is enabled as {}",
+                        submarine,
+                        name,
+                        package.package_id()
+                    ),
+                    RequirementStatus::Disabled(path) => log::error!(
+                        "Submarine {} requires {}, which is installed at {} but not enabled",
+                        submarine,
+                        name,
+                        path.display()
+                    ),
+                    RequirementStatus::Missing => log::error!(
+                        "Submarine {} requires {}, which is not installed",
+                        submarine,
+                        name
+                    ),
+                }
+            }
+            if !report.unlisted_packages.is_empty() {
+                warn!(
+                    "Submarine {} uses content from packages it doesn't list as required: {:?}",
+                    submarine,
+                    report
+                        .unlisted_packages
+                        .iter()
+                        .map(|v| v.package_id())
+                        .collect::<Vec<_>>()
+                );
+            }
+        }
+
+        for (package, files) in &loaded_content_files {
+            for (asset_type, asset) in files.submarine_assets() {
+                let issues = check_integrity(&asset.sub.submarine_info);
+                if issues.is_empty() {
+                    continue;
+                }
+                warn!(
+                    "{} {} of {} has integrity issues: {}",
+                    asset_type,
+                    asset.file_path,
+                    package.package_id(),
+                    issues
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                );
+                conflicts.submarine_integrity.push(SubmarineIntegrity {
+                    submarine: SubmarineRef::new(package, asset_type, asset),
+                    issues,
+                });
+            }
+        }
+
+        conflicts.dangling_references = find_dangling_references(&loaded_content_files);
+        for package in &conflicts.dangling_references {
+            for reference in &package.references {
+                log::error!("{}: {}", package.package.package_id(), reference);
+            }
+        }
+
+        let resolved_items = ResolvedItems::new(&loaded_content_files);
+        conflicts.crafting_exploits =
+            find_crafting_exploits(&loaded_content_files, &resolved_items);
+        for cycle in &conflicts.crafting_exploits.cycles {
+            warn!("Material multiplying loop: {}", cycle);
+        }
+        for arbitrage in &conflicts.crafting_exploits.arbitrage {
+            warn!("Profitable crafting: {}", arbitrage);
+        }
+
+        conflicts.obtainability = find_item_sources(&loaded_content_files, &resolved_items);
+        for item in conflicts.obtainability.unobtainable() {
+            warn!(
+                "Item {} of {} can't be obtained by players",
+                item.item,
+                item.package.package_id()
+            );
+        }
+
+        conflicts.store_prices = store_price_report(&loaded_content_files, &resolved_items);
+        for price_override in &conflicts.store_prices.price_overrides {
+            warn!(
+                "Item {} has wildly different prices between packages: {:?}",
+                price_override.item,
+                price_override
+                    .prices
+                    .iter()
+                    .map(|(package, price)| format!("{}: {}", package.package_id(), price))
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        conflicts.variants = check_variants(&loaded_content_files, &resolved_items);
+        for issue in &conflicts.variants.issues {
+            log::error!("{}", issue);
+        }
+        for affected in &conflicts.variants.affected {
+            warn!("{}", affected);
+        }
+
+        conflicts.talent_trees = check_talent_trees(&loaded_content_files);
+        for problem in &conflicts.talent_trees {
+            log::error!("{}", problem);
+        }
+
+        conflicts.events = check_events(&loaded_content_files);
+        for issue in &conflicts.events {
+            log::error!("{}", issue);
+        }
+
+        conflicts.conversations = check_conversations(&loaded_content_files);
+        for issue in &conflicts.conversations.issues {
+            warn!("{}", issue);
+        }
+        for duplicate in &conflicts.conversations.duplicates {
+            warn!(
+                "Conversation line \"{}\" ({}) is shipped by multiple packages: {:?}",
+                duplicate.line,
+                duplicate.language,
+                duplicate
+                    .packages
+                    .iter()
+                    .map(|v| v.package_id())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        conflicts.localization = localization_coverage(&loaded_content_files);
+        for package in &conflicts.localization {
+            for language in package.languages.iter().filter(|v| !v.missing.is_empty()) {
+                warn!(
+                    "{}: {} of {} referenced texts have no {} translation",
+                    package.package.package_id(),
+                    language.missing.len(),
+                    language.referenced,
+                    language.language
+                );
+            }
+            if !package.unused_tags.is_empty() {
+                warn!(
+                    "{} ships text tags no prefab uses: {:?}",
+                    package.package.package_id(),
+                    package
+                        .unused_tags
+                        .iter()
+                        .map(|(language, tag)| format!("{} ({})", tag, language))
+                        .collect::<Vec<_>>()
+                );
+            }
+        }
         let _ = output
             .send(Progress::Finished(
                 Arc::new(loaded_content_files),
@@ -324,45 +620,77 @@ pub fn load(
 
 #[derive(Default, Debug)]
 pub struct Conflicts {
-    items_items: HashMap<String, IdCheck>,
-    item_assemblies_item_assemblies: HashMap<String, IdCheck>,
-    talents_items: HashMap<String, IdCheck>,
-    npc_sets_sets: HashMap<String, IdCheck>,
-    slideshows_slideshows: HashMap<String, IdCheck>,
-    talent_trees_trees: HashMap<String, IdCheck>,
-    level_generation_parameters_biomes: HashMap<String, IdCheck>,
-    level_generation_parameters_level_generation_params: HashMap<String, IdCheck>,
-    ballast_flora_prefabs: HashMap<String, IdCheck>,
-    start_items_sets: HashMap<String, IdCheck>,
-    level_object_prefabs_prefabs: HashMap<String, IdCheck>,
-    afflictions_affliction_prefabs: HashMap<String, IdCheck>,
-    random_events_traitor_event_prefabs: HashMap<String, IdCheck>,
-    random_events_event_prefabs: HashMap<String, IdCheck>,
-    random_events_event_sprites: HashMap<String, IdCheck>,
-    random_events_event_sets: HashMap<String, IdCheck>,
-    structures_prefabs: HashMap<String, IdCheck>,
-    upgrade_modules_categories: HashMap<String, IdCheck>,
-    upgrade_modules_prefabs: HashMap<String, IdCheck>,
-    ruin_configs_ruin_generation_params: HashMap<String, IdCheck>,
-    outpost_configs_outpost_generation_params: HashMap<String, IdCheck>,
-    wreck_ai_configs_wreck_ai_configs: HashMap<String, IdCheck>,
-    cave_generation_params_cave_generation_params: HashMap<String, IdCheck>,
-    particle_prefabs_particle_prefabs: HashMap<String, IdCheck>,
-    event_manager_settings_event_manager_settings: HashMap<String, IdCheck>,
-    npc_personality_traits_npc_personality_traits: HashMap<String, IdCheck>,
-    jobs_item_repair_priorities: HashMap<String, IdCheck>,
-    jobs_jobs: HashMap<String, IdCheck>,
-    corpse_prefabs_corpse_prefabs: HashMap<String, IdCheck>,
-    sound_prefabs_damage_sound_prefabs: HashMap<String, IdCheck>,
-    sound_prefabs_background_music_prefabs: HashMap<String, IdCheck>,
-    sound_prefabs_gui_sound_prefabs: HashMap<String, IdCheck>,
-    decal_prefabs_decal_prefabs: HashMap<String, IdCheck>,
-    location_types_location_types: HashMap<String, IdCheck>,
-    mission_prefabs_mission_prefabs: HashMap<String, IdCheck>,
-    order_prefabs_order_prefabs: HashMap<String, IdCheck>,
-    order_prefabs_order_category_icons: HashMap<String, IdCheck>,
-    faction_prefabs_faction_prefabs: HashMap<String, IdCheck>,
-    tutorial_prefabs_tutorial_prefabs: HashMap<String, IdCheck>,
+    items_items: HashMap<Identifier, IdCheck>,
+    item_assemblies_item_assemblies: HashMap<Identifier, IdCheck>,
+    talents_items: HashMap<Identifier, IdCheck>,
+    npc_sets_sets: HashMap<Identifier, IdCheck>,
+    slideshows_slideshows: HashMap<Identifier, IdCheck>,
+    talent_trees_trees: HashMap<Identifier, IdCheck>,
+    level_generation_parameters_biomes: HashMap<Identifier, IdCheck>,
+    level_generation_parameters_level_generation_params: HashMap<Identifier, IdCheck>,
+    ballast_flora_prefabs: HashMap<Identifier, IdCheck>,
+    start_items_sets: HashMap<Identifier, IdCheck>,
+    level_object_prefabs_prefabs: HashMap<Identifier, IdCheck>,
+    afflictions_affliction_prefabs: HashMap<Identifier, IdCheck>,
+    random_events_traitor_event_prefabs: HashMap<Identifier, IdCheck>,
+    random_events_event_prefabs: HashMap<Identifier, IdCheck>,
+    random_events_event_sprites: HashMap<Identifier, IdCheck>,
+    random_events_event_sets: HashMap<Identifier, IdCheck>,
+    structures_prefabs: HashMap<Identifier, IdCheck>,
+    upgrade_modules_categories: HashMap<Identifier, IdCheck>,
+    upgrade_modules_prefabs: HashMap<Identifier, IdCheck>,
+    ruin_configs_ruin_generation_params: HashMap<Identifier, IdCheck>,
+    outpost_configs_outpost_generation_params: HashMap<Identifier, IdCheck>,
+    wreck_ai_configs_wreck_ai_configs: HashMap<Identifier, IdCheck>,
+    cave_generation_params_cave_generation_params: HashMap<Identifier, IdCheck>,
+    particle_prefabs_particle_prefabs: HashMap<Identifier, IdCheck>,
+    event_manager_settings_event_manager_settings: HashMap<Identifier, IdCheck>,
+    npc_personality_traits_npc_personality_traits: HashMap<Identifier, IdCheck>,
+    jobs_item_repair_priorities: HashMap<Identifier, IdCheck>,
+    jobs_jobs: HashMap<Identifier, IdCheck>,
+    corpse_prefabs_corpse_prefabs: HashMap<Identifier, IdCheck>,
+    sound_prefabs_damage_sound_prefabs: HashMap<Identifier, IdCheck>,
+    sound_prefabs_background_music_prefabs: HashMap<Identifier, IdCheck>,
+    sound_prefabs_gui_sound_prefabs: HashMap<Identifier, IdCheck>,
+    decal_prefabs_decal_prefabs: HashMap<Identifier, IdCheck>,
+    location_types_location_types: HashMap<Identifier, IdCheck>,
+    mission_prefabs_mission_prefabs: HashMap<Identifier, IdCheck>,
+    order_prefabs_order_prefabs: HashMap<Identifier, IdCheck>,
+    order_prefabs_order_category_icons: HashMap<Identifier, IdCheck>,
+    faction_prefabs_faction_prefabs: HashMap<Identifier, IdCheck>,
+    tutorial_prefabs_tutorial_prefabs: HashMap<Identifier, IdCheck>,
+    pub key_collisions: Vec<KeyCollision>,
+    pub game_version: Option<Version>,
+    pub outdated_packages: Vec<OutdatedPackage>,
+    /// Packages shipping C# or Lua, their XML content is checked but not what the code changes at runtime
+    pub packages_with_code: Vec<Arc<AnyContentPackage>>,
+    pub submarine_conflicts: Vec<SubmarineConflict>,
+    pub missing_prefabs: Vec<MissingPrefabs>,
+    pub required_packages: Vec<RequiredPackagesReport>,
+    pub submarine_integrity: Vec<SubmarineIntegrity>,
+    pub dangling_references: Vec<PackageReferences>,
+    pub crafting_exploits: CraftingExploits,
+    pub obtainability: ObtainabilityReport,
+    pub store_prices: StorePriceReport,
+    pub variants: VariantReport,
+    pub talent_trees: Vec<TalentTreeProblem>,
+    pub events: Vec<EventIssue>,
+    pub conversations: ConversationReport,
+    pub localization: Vec<PackageLocalization>,
+}
+
+#[derive(Debug)]
+pub struct SubmarineIntegrity {
+    pub submarine: SubmarineRef,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+/// Different identifiers that hash to the same network key, the game can't tell these prefabs apart in multiplayer
+#[derive(Debug)]
+pub struct KeyCollision {
+    pub prefab_type: &'static str,
+    pub key: u32,
+    pub identifiers: Vec<(Identifier, Vec<Arc<AnyContentPackage>>)>,
 }
 
 macro_rules! build_conflict_type_enum {
@@ -377,7 +705,7 @@ macro_rules! build_conflict_type_enum {
         }
 
         impl ConflictType {
-            pub fn get_conflict_by_type<'a>(&self, conflicts: &'a Conflicts) -> &'a HashMap<String, IdCheck> {
+            pub fn get_conflict_by_type<'a>(&self, conflicts: &'a Conflicts) -> &'a HashMap<Identifier, IdCheck> {
                 match self {
                     $(
                         Self::$item_name => &conflicts.$field_name,
@@ -386,10 +714,18 @@ macro_rules! build_conflict_type_enum {
             }
 
             /// Currently implemented as a slow lookup
-            pub fn get_conflict_file_by_type<'a>(&self, files: &'a ContentFiles, item_identifier: &str) -> Option<&'a String> {
+            pub fn get_conflict_file_by_type<'a>(&self, files: &'a ContentFiles, item_identifier: &Identifier) -> Option<&'a String> {
+                match self {
+                    $(
+                        Self::$item_name => files.$content_file.iter().find(|v| v.$overridable_field.iter().any(|v| v.value.get_identifier() == item_identifier)).map(|v| &v.file_path),
+                    )*
+                }
+            }
+
+            pub fn get_conflict_file_paths_by_type<'a>(&self, file_paths: &'a ContentFilePaths,) -> &'a Vec<String> {
                 match self {
                     $(
-                        Self::$item_name => files.$content_file.iter().find(|v| v.$overridable_field.iter().any(|v| v.value.get_identifier().to_string() == item_identifier)).map(|v| &v.file_path),
+                        Self::$item_name => &file_paths.$content_file,
                     )*
                 }
             }
@@ -1,5 +1,6 @@
 pub mod content_files;
 pub mod date_time;
+pub mod identifier;
 pub mod item_components;
 pub mod math;
 pub mod prefabs;
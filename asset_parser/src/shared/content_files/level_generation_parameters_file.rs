@@ -4,6 +4,7 @@ use glam::Vec2;
 use roxmltree::Node;
 
 use crate::shared::{
+    identifier::Identifier,
     prefabs::{
         item_prefab::{BarotraumaSprite, Color, DoesNotExistError},
         map_generation_params::Point,
@@ -82,8 +83,8 @@ impl XmlContentFile for LevelGenerationParametersFile {
 
 #[derive(Debug, Clone)]
 pub struct Biome {
-    pub identifier: String,
-    pub old_identifier: Option<String>,
+    pub identifier: Identifier,
+    pub old_identifier: Option<Identifier>,
     pub name: Option<String>,
     pub description: Option<String>,
     pub is_end_biome: bool,
@@ -99,11 +100,11 @@ impl Biome {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(std::borrow::ToOwned::to_owned)
+            .map(Identifier::from)
             .unwrap();
         let old_identifier = element
             .attribute_ignore_ascii_case("oldidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let name = element
             .attribute_ignore_ascii_case("name")
             .map(std::borrow::ToOwned::to_owned);
@@ -194,10 +195,10 @@ impl SubmarineAvailability {
 
 #[derive(Debug, Clone)]
 pub struct LevelGenerationParameters {
-    pub identifier: String,
-    pub old_identifier: Option<String>,
+    pub identifier: Identifier,
+    pub old_identifier: Option<Identifier>,
     pub properties: LevelGenerationParametersProperties,
-    pub allowed_biome_identifiers: Option<Vec<String>>,
+    pub allowed_biome_identifiers: Option<Vec<Identifier>>,
     pub background_sprite: Option<BarotraumaSprite>,
     pub background_top_sprite: Option<BarotraumaSprite>,
     pub wall_sprite: Option<BarotraumaSprite>,
@@ -211,19 +212,17 @@ pub struct LevelGenerationParameters {
 
 impl LevelGenerationParameters {
     pub fn new(element: Node) -> Self {
-        let identifier = element
-            .attribute_ignore_ascii_case("identifier")
-            .map(std::borrow::ToOwned::to_owned)
-            .unwrap_or(element.tag_name().name().to_owned());
+        let identifier = element.attribute_ignore_ascii_case("identifier").map_or(
+            Identifier::from(element.tag_name().name()),
+            Identifier::from,
+        );
         let old_identifier = element
             .attribute_ignore_ascii_case("oldidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let properties = LevelGenerationParametersProperties::new(element);
-        let allowed_biome_identifiers = element.attribute_ignore_ascii_case("biomes").map(|v| {
-            v.split(',')
-                .map(std::borrow::ToOwned::to_owned)
-                .collect::<Vec<_>>()
-        });
+        let allowed_biome_identifiers = element
+            .attribute_ignore_ascii_case("biomes")
+            .map(Identifier::split_list);
 
         let mut background_sprite = None;
         let mut background_top_sprite = None;
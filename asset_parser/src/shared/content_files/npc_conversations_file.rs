@@ -1,6 +1,9 @@
 use roxmltree::Node;
 
-use crate::shared::util::{NodeExp, XmlContentFile};
+use crate::shared::{
+    identifier::Identifier,
+    util::{NodeExp, XmlContentFile},
+};
 
 #[derive(Debug)]
 pub struct NPCConversationFile {
@@ -42,7 +45,7 @@ impl XmlContentFile for NPCConversationFile {
 pub struct NPCConversation {
     pub line: String,
     pub speaker_index: u32,
-    pub allowed_jobs: Vec<String>,
+    pub allowed_jobs: Vec<Identifier>,
     pub flags: Vec<String>,
     pub min_intensity: Option<f32>,
     pub max_intensity: Option<f32>,
@@ -62,11 +65,7 @@ impl NPCConversation {
             .unwrap();
         let allowed_jobs = element
             .attribute_ignore_ascii_case("allowedjobs")
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            })
+            .map(Identifier::split_list)
             .unwrap_or_default();
         let flags = element
             .attribute_ignore_ascii_case("speakertags")
@@ -1,6 +1,8 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, Utc};
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, Timelike, Utc,
+};
 
 #[derive(Debug, Clone)]
 pub struct SerializableDateTime(pub DateTime<Utc>);
@@ -52,6 +54,22 @@ impl FromStr for SerializableDateTime {
     }
 }
 
+/// Written in UTC the way the game writes it, e.g. `2024Y 3M 14D 9HR 5MIN 30SEC UTC`
+impl Display for SerializableDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}Y {}M {}D {}HR {}MIN {}SEC UTC",
+            self.0.year(),
+            self.0.month(),
+            self.0.day(),
+            self.0.hour(),
+            self.0.minute(),
+            self.0.second()
+        )
+    }
+}
+
 #[derive(Debug)]
 pub struct InvalidDateError;
 pub struct InvalidTimeZoneError;
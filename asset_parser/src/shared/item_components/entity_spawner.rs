@@ -4,7 +4,8 @@ use glam::Vec2;
 use roxmltree::Node;
 
 use crate::shared::{
-    prefabs::item_prefab::DoesNotExistError, submarine_info::Vector2, util::NodeExp,
+    identifier::Identifier, prefabs::item_prefab::DoesNotExistError, submarine_info::Vector2,
+    util::NodeExp,
 };
 
 use super::ItemComponent;
@@ -13,8 +14,8 @@ use super::ItemComponent;
 pub struct EntitySpawnerComponent {
     pub item: ItemComponent,
 
-    pub item_identifier: Option<String>,
-    pub species_name: Option<String>,
+    pub item_identifier: Option<Identifier>,
+    pub species_name: Option<Identifier>,
     pub only_spawn_when_crew_in_range: bool,
     pub crew_area_shape: AreaShape,
     pub crew_area_bounds: Vec2,
@@ -38,10 +39,10 @@ impl EntitySpawnerComponent {
 
             item_identifier: element
                 .attribute_ignore_ascii_case("itemidentifier")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             species_name: element
                 .attribute_ignore_ascii_case("speciesname")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             only_spawn_when_crew_in_range: element
                 .attribute_ignore_ascii_case("onlyspawnwhencrewinrange")
                 .map(|v| v.to_lowercase().parse().unwrap())
@@ -25,7 +25,7 @@ impl FunctionComponent {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum FunctionType {
     Round,
     Ceil,
@@ -1,6 +1,6 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::ItemComponent;
 
@@ -14,7 +14,7 @@ pub struct GeneticMaterialComponent {
     pub selected_tainted_effect: String,
     pub condition_increase_on_combine_min: f32,
     pub condition_increase_on_combine_max: f32,
-    pub name_identifier: Option<String>,
+    pub name_identifier: Option<Identifier>,
 }
 
 impl GeneticMaterialComponent {
@@ -45,7 +45,7 @@ impl GeneticMaterialComponent {
                 .map_or(8.0, |v| v.to_lowercase().parse().unwrap()),
             name_identifier: element
                 .attribute_ignore_ascii_case("nameidentifier")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
         }
     }
 }
@@ -31,7 +31,7 @@ impl OscillatorComponent {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum WaveType {
     Pulse,
     Sawtooth,
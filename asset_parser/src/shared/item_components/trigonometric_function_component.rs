@@ -30,7 +30,7 @@ impl TrigonometricFunctionComponent {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum FunctionType {
     Sin,
     Cos,
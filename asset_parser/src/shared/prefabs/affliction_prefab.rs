@@ -3,7 +3,7 @@ use std::{collections::HashMap, str::FromStr};
 use bitfield_struct::bitfield;
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::{
     human_prefab::StatType,
@@ -14,9 +14,9 @@ use super::{
 #[derive(Debug)]
 pub struct AfflictionPrefab {
     pub prefab_type: AfflictionPrefabType,
-    pub identifier: String,
+    pub identifier: Identifier,
     pub affliction_type: Option<String>,
-    pub translation_identifier: Option<String>,
+    pub translation_identifier: Option<Identifier>,
     pub fallback_name: Option<String>,
     pub fallback_description: Option<String>,
     pub is_buff: bool,
@@ -27,7 +27,7 @@ pub struct AfflictionPrefab {
     pub base_heal_cost: u32,
     pub ignore_treatment_if_afflicted_by: Option<Vec<String>>,
     pub duration: f32,
-    pub name_identifier: Option<String>,
+    pub name_identifier: Option<Identifier>,
     pub limb_specific: bool,
     pub indicator_limb: Option<LimbType>,
     pub hide_icon_after_delay: bool,
@@ -63,14 +63,14 @@ impl AfflictionPrefab {
     pub fn new(element: Node, prefab_type: AfflictionPrefabType) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let affliction_type = element
             .attribute_ignore_ascii_case("type")
             .map(|v| v.to_owned());
         let translation_identifier = element
             .attribute_ignore_ascii_case("translationoverride")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let fallback_name = element
             .attribute_ignore_ascii_case("name")
             .map(|v| v.to_owned());
@@ -112,7 +112,7 @@ impl AfflictionPrefab {
             .map_or(0.0, |v| v.parse::<f32>().unwrap());
         let name_identifier = element
             .attribute_ignore_ascii_case("nameidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let limb_specific = element
             .attribute_ignore_ascii_case("limbspecific")
             .map_or(false, |v| v.to_lowercase().parse::<bool>().unwrap());
@@ -284,7 +284,7 @@ impl AfflictionPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -301,7 +301,7 @@ pub enum AfflictionPrefabType {
 
 #[derive(Debug)]
 pub struct Description {
-    pub text_identifier: Option<String>,
+    pub text_identifier: Option<Identifier>,
     pub text_fallback: Option<String>,
     pub min_strength: f32,
     pub max_strength: f32,
@@ -312,7 +312,7 @@ impl Description {
     pub fn new(element: Node) -> Self {
         let text_identifier = element
             .attribute_ignore_ascii_case("textidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let text_fallback = element
             .attribute_ignore_ascii_case("text")
             .map(|v| v.to_owned());
@@ -3,7 +3,7 @@ use std::{collections::HashMap, str::FromStr};
 use glam::Vec2;
 use roxmltree::Node;
 
-use crate::shared::{submarine_info::Vector2, util::NodeExp};
+use crate::shared::{identifier::Identifier, submarine_info::Vector2, util::NodeExp};
 
 use super::{
     item_assembly_prefab::Rect,
@@ -13,7 +13,7 @@ use super::{
 
 #[derive(Debug)]
 pub struct BallastFloraPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub name: String,
     pub properties: BallastFloraProperties,
     pub branch_atlas: Option<String>,
@@ -33,8 +33,8 @@ impl BallastFloraPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let name = element
             .attribute_ignore_ascii_case("name")
             .unwrap()
@@ -113,14 +113,14 @@ impl BallastFloraPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
 #[derive(Debug)]
 pub struct AITarget {
-    pub tags: Vec<String>,
+    pub tags: Vec<Identifier>,
     pub priority: u32,
 }
 
@@ -129,7 +129,7 @@ impl AITarget {
         Self {
             tags: element
                 .attribute_ignore_ascii_case("tags")
-                .map_or(Vec::new(), |v| v.split(',').map(|v| v.to_owned()).collect()),
+                .map_or(Vec::new(), Identifier::split_list),
             priority: element
                 .attribute_ignore_ascii_case("priority")
                 .map_or(0, |v| v.parse().unwrap()),
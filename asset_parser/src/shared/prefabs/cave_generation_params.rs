@@ -2,14 +2,14 @@ use std::collections::HashMap;
 
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::item_prefab::BarotraumaSprite;
 
 //TODO: remove clone here
 #[derive(Debug, Clone)]
 pub struct CaveGenerationParams {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub commonness: f32,
     pub min_width: u32,
     pub max_width: u32,
@@ -28,8 +28,8 @@ impl CaveGenerationParams {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let commonness = element
             .attribute_ignore_ascii_case("commonness")
             .map_or(1.0, |v| v.parse::<f32>().unwrap());
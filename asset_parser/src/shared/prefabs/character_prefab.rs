@@ -3,13 +3,14 @@ use std::collections::HashMap;
 use glam::Vec2;
 use roxmltree::Node;
 
-use crate::shared::{submarine_info::Vector2, util::NodeExp};
+use crate::shared::{identifier::Identifier, submarine_info::Vector2, util::NodeExp};
 
 use super::level_object_prefab::RoundSound;
 
 #[derive(Debug)]
 pub struct CharacterPrefab {
-    pub variant_of: Option<String>,
+    pub species_name: Option<Identifier>,
+    pub variant_of: Option<Identifier>,
     pub character_info_prefab: Option<CharacterInfoPrefab>,
     pub sounds: Vec<RoundSound>,
     pub is_humanoid: bool,
@@ -17,10 +18,14 @@ pub struct CharacterPrefab {
 
 impl CharacterPrefab {
     pub fn new(element: Node) -> Self {
+        let species_name = element
+            .attribute_ignore_ascii_case("speciesname")
+            .or(element.attribute_ignore_ascii_case("name"))
+            .map(Identifier::from);
         let variant_of = element
             .attribute_ignore_ascii_case("inherit")
             .or(element.attribute_ignore_ascii_case("variantof"))
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
 
         let heads_element = element
             .children()
@@ -55,6 +60,7 @@ impl CharacterPrefab {
             .is_some_and(|v| v.to_lowercase().parse::<bool>().unwrap());
 
         Self {
+            species_name,
             variant_of,
             character_info_prefab,
             sounds,
@@ -139,7 +145,7 @@ impl CharacterInfoPrefab {
 
 #[derive(Debug)]
 pub struct HeadPreset {
-    pub tags: Vec<String>,
+    pub tags: Vec<Identifier>,
     pub sheet_index: Vec2,
 }
 
@@ -147,11 +153,7 @@ impl HeadPreset {
     pub fn new(element: Node) -> Self {
         let tags = element
             .attribute_ignore_ascii_case("tags")
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            })
+            .map(Identifier::split_list)
             .unwrap_or_default();
         let sheet_index = element
             .attribute_ignore_ascii_case("sheetindex")
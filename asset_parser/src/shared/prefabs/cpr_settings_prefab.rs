@@ -1,6 +1,6 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 #[derive(Debug)]
 pub struct CPRSettings {
@@ -13,7 +13,7 @@ pub struct CPRSettings {
     pub stabilization_max: f32,
     pub damage_skill_threshold: f32,
     pub damage_skill_multiplier: f32,
-    pub insufficient_skill_affliction_identifier: String,
+    pub insufficient_skill_affliction_identifier: Identifier,
 }
 
 impl CPRSettings {
@@ -49,7 +49,7 @@ impl CPRSettings {
             .map_or(0.1, |v| v.parse::<f32>().unwrap().clamp(0.0, 100.0));
         let insufficient_skill_affliction_identifier = element
             .attribute_ignore_ascii_case("insufficientskillaffliction")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         Self {
             revive_chance_per_skill,
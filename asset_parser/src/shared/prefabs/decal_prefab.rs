@@ -1,12 +1,12 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::item_prefab::{BarotraumaSprite, Color};
 
 #[derive(Debug)]
 pub struct DecalPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub color: Color,
     pub lifetime: f32,
     pub fade_out_time: f32,
@@ -16,7 +16,7 @@ pub struct DecalPrefab {
 
 impl DecalPrefab {
     pub fn new(element: Node) -> Self {
-        let identifier = element.tag_name().name().to_owned();
+        let identifier = Identifier::from(element.tag_name().name());
         let color = element.attribute_ignore_ascii_case("color").map_or(
             Color::Simple {
                 r: 1.0,
@@ -55,7 +55,7 @@ impl DecalPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
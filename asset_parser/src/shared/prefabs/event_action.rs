@@ -0,0 +1,217 @@
+use roxmltree::Node;
+
+use crate::shared::{identifier::Identifier, util::NodeExp};
+
+/// Elements of scripted and traitor events that configure the event instead of being actions
+const NON_ACTION_ELEMENTS: [&str; 4] = [
+    "icon",
+    "reputationrequirement",
+    "missionrequirement",
+    "levelrequirement",
+];
+
+#[derive(Debug)]
+pub enum EventActionKind {
+    Conversation {
+        text: Option<String>,
+        speaker_tag: Option<Identifier>,
+    },
+    GoTo {
+        name: Identifier,
+    },
+    Label {
+        name: Identifier,
+    },
+    Tag {
+        criteria: Option<String>,
+        tag: Option<Identifier>,
+    },
+    Spawn {
+        species_name: Option<Identifier>,
+        npc_set: Option<Identifier>,
+        npc: Option<Identifier>,
+        item: Option<Identifier>,
+        target_tag: Option<Identifier>,
+    },
+    Trigger {
+        target1_tag: Option<Identifier>,
+        target2_tag: Option<Identifier>,
+    },
+    Mission {
+        mission: Option<Identifier>,
+        mission_tag: Option<Identifier>,
+    },
+    CheckItem {
+        items: Vec<Identifier>,
+        item_tags: Vec<Identifier>,
+        target_tag: Option<Identifier>,
+    },
+    RemoveItem {
+        items: Vec<Identifier>,
+        item_tags: Vec<Identifier>,
+        target_tag: Option<Identifier>,
+    },
+    TriggerEvent {
+        identifier: Option<Identifier>,
+    },
+    EventLog {
+        text: Option<String>,
+    },
+    MessageBox {
+        header: Option<String>,
+        text: Option<String>,
+    },
+    End,
+    /// Any other action, named without the `Action` suffix
+    Other(String),
+}
+
+impl EventActionKind {
+    /// Element name of the action without the `Action` suffix
+    pub fn name(&self) -> &str {
+        match self {
+            EventActionKind::Conversation { .. } => "conversation",
+            EventActionKind::GoTo { .. } => "goto",
+            EventActionKind::Label { .. } => "label",
+            EventActionKind::Tag { .. } => "tag",
+            EventActionKind::Spawn { .. } => "spawn",
+            EventActionKind::Trigger { .. } => "trigger",
+            EventActionKind::Mission { .. } => "mission",
+            EventActionKind::CheckItem { .. } => "checkitem",
+            EventActionKind::RemoveItem { .. } => "removeitem",
+            EventActionKind::TriggerEvent { .. } => "triggerevent",
+            EventActionKind::EventLog { .. } => "eventlog",
+            EventActionKind::MessageBox { .. } => "messagebox",
+            EventActionKind::End => "end",
+            EventActionKind::Other(name) => name,
+        }
+    }
+}
+
+/// Actions run depending on the outcome of an action, like `Success`/`Failure` or a conversation option
+#[derive(Debug)]
+pub struct ActionBranch {
+    /// `success`, `failure` or the text of a conversation option
+    pub name: String,
+    pub actions: Vec<EventAction>,
+}
+
+#[derive(Debug)]
+pub struct EventAction {
+    pub kind: EventActionKind,
+    pub branches: Vec<ActionBranch>,
+}
+
+fn identifier_list(element: Node, name: &str) -> Vec<Identifier> {
+    element
+        .attribute_ignore_ascii_case(name)
+        .map(Identifier::split_list)
+        .unwrap_or_default()
+}
+
+impl EventAction {
+    /// Parses the actions among the children of an event or a branch
+    pub fn load_actions(element: Node) -> Vec<Self> {
+        element
+            .children()
+            .filter(Node::is_element)
+            .filter(|child| {
+                !NON_ACTION_ELEMENTS
+                    .iter()
+                    .any(|v| child.tag_name().name().eq_ignore_ascii_case(v))
+            })
+            .map(|child| Self::new(child))
+            .collect()
+    }
+
+    pub fn new(element: Node) -> Self {
+        let identifier = |name: &str| {
+            element
+                .attribute_ignore_ascii_case(name)
+                .map(Identifier::from)
+        };
+        let string = |name: &str| {
+            element
+                .attribute_ignore_ascii_case(name)
+                .map(std::borrow::ToOwned::to_owned)
+        };
+
+        //the game appends the Action suffix when looking up the type, so both forms are accepted
+        let tag_name = element.tag_name().name().to_lowercase();
+        let name = tag_name.strip_suffix("action").unwrap_or(&tag_name);
+        let kind = match name {
+            "conversation" => EventActionKind::Conversation {
+                text: string("text"),
+                speaker_tag: identifier("speakertag"),
+            },
+            "goto" => EventActionKind::GoTo {
+                name: identifier("name").unwrap_or_default(),
+            },
+            "label" => EventActionKind::Label {
+                name: identifier("name").unwrap_or_default(),
+            },
+            "tag" => EventActionKind::Tag {
+                criteria: string("criteria"),
+                tag: identifier("tag"),
+            },
+            "spawn" => EventActionKind::Spawn {
+                species_name: identifier("speciesname"),
+                npc_set: identifier("npcsetidentifier"),
+                npc: identifier("npcidentifier"),
+                item: identifier("itemidentifier"),
+                target_tag: identifier("targettag"),
+            },
+            "trigger" => EventActionKind::Trigger {
+                target1_tag: identifier("target1tag"),
+                target2_tag: identifier("target2tag"),
+            },
+            "mission" => EventActionKind::Mission {
+                mission: identifier("missionidentifier"),
+                mission_tag: identifier("missiontag"),
+            },
+            "checkitem" => EventActionKind::CheckItem {
+                items: identifier_list(element, "itemidentifiers"),
+                item_tags: identifier_list(element, "itemtags"),
+                target_tag: identifier("targettag"),
+            },
+            "removeitem" => EventActionKind::RemoveItem {
+                items: identifier_list(element, "itemidentifiers"),
+                item_tags: identifier_list(element, "itemtags"),
+                target_tag: identifier("targettag"),
+            },
+            "triggerevent" => EventActionKind::TriggerEvent {
+                identifier: identifier("identifier"),
+            },
+            "eventlog" => EventActionKind::EventLog {
+                text: string("text"),
+            },
+            "messagebox" => EventActionKind::MessageBox {
+                header: string("header"),
+                text: string("text"),
+            },
+            "end" => EventActionKind::End,
+            _ => EventActionKind::Other(name.to_owned()),
+        };
+
+        let branches = element
+            .children()
+            .filter(Node::is_element)
+            .filter_map(|child| {
+                let name = match child.tag_name().name().to_lowercase().as_str() {
+                    "option" => child
+                        .attribute_ignore_ascii_case("text")
+                        .unwrap_or_default()
+                        .to_owned(),
+                    name @ ("success" | "failure") => name.to_owned(),
+                    _ => return None,
+                };
+                Some(ActionBranch {
+                    name,
+                    actions: Self::load_actions(child),
+                })
+            })
+            .collect();
+
+        Self { kind, branches }
+    }
+}
@@ -1,10 +1,10 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 #[derive(Debug)]
 pub struct EventManagerSettings {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub event_threshold_increase: f32,
     pub default_event_threshold: f32,
     pub event_cooldown: f32,
@@ -15,7 +15,7 @@ pub struct EventManagerSettings {
 
 impl EventManagerSettings {
     pub fn new(element: Node) -> Self {
-        let identifier = element.tag_name().name().to_lowercase();
+        let identifier = Identifier::from(element.tag_name().name());
         let event_threshold_increase = element
             .attribute_ignore_ascii_case("eventthresholdincrease")
             .map_or(0.0005, |v| v.parse::<f32>().unwrap());
@@ -46,7 +46,7 @@ impl EventManagerSettings {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
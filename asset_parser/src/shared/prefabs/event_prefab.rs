@@ -9,20 +9,22 @@ use roxmltree::Node;
 
 use crate::shared::{
     content_files::level_generation_parameters_file,
+    identifier::Identifier,
     prefabs::item_prefab::{BarotraumaSprite, Color},
     util::NodeExp,
 };
 
 use super::{
+    event_action::EventAction,
     item_prefab::DoesNotExistError,
     level_object_prefab::{ComparisonOperatorType, PropertyConditional},
 };
 
 #[derive(Debug)]
 pub struct EventPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub event_type: EventType,
-    pub biome_identifier: Option<String>,
+    pub biome_identifier: Option<Identifier>,
     pub faction: Option<String>,
     pub commonness: f32,
     pub probability: f32,
@@ -30,13 +32,15 @@ pub struct EventPrefab {
     pub unlock_path_event: bool,
     pub unlock_path_tooltip: Option<String>,
     pub unlock_path_reputation: i32,
+    /// Action tree of scripted and traitor events
+    pub actions: Vec<EventAction>,
 }
 
 impl EventPrefab {
-    pub fn new(element: Node, fallback_identifier: Option<String>) -> Self {
+    pub fn new(element: Node, fallback_identifier: Option<Identifier>) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .or(fallback_identifier)
             .unwrap();
         let event_type = match element.tag_name().name() {
@@ -50,7 +54,7 @@ impl EventPrefab {
         };
         let biome_identifier = element
             .attribute_ignore_ascii_case("biome")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let faction = element
             .attribute_ignore_ascii_case("faction")
             .map(|v| v.to_owned());
@@ -76,6 +80,11 @@ impl EventPrefab {
         let unlock_path_reputation = element
             .attribute_ignore_ascii_case("unlockpathreputation")
             .map_or(0, |v| v.parse::<i32>().unwrap());
+        let actions = if matches!(event_type, EventType::Scripted | EventType::Traitor) {
+            EventAction::load_actions(element)
+        } else {
+            Vec::new()
+        };
 
         Self {
             identifier,
@@ -88,10 +97,11 @@ impl EventPrefab {
             unlock_path_event,
             unlock_path_tooltip,
             unlock_path_reputation,
+            actions,
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -117,7 +127,7 @@ pub struct TraitorEventPrefab {
     pub secondary_traitor_percentage: f32,
     pub allow_accusing_secondary_traitor: bool,
     pub money_penalty_for_unfounded_traitor_accusation: u32,
-    pub tags: Option<HashSet<String>>,
+    pub tags: Option<HashSet<Identifier>>,
     pub required_completed_tags: Option<HashSet<String>>,
     pub steal_percentage_of_experience: f32,
     pub is_chainable: bool,
@@ -169,7 +179,7 @@ impl TraitorEventPrefab {
             .map_or(100, |v| v.parse::<u32>().unwrap());
         let tags = element
             .attribute_ignore_ascii_case("tags")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect());
+            .map(|v| Identifier::split_list(v).into_iter().collect());
         let required_completed_tags = element
             .attribute_ignore_ascii_case("requiredcompletedtags")
             .map(|v| v.split(',').map(|v| v.to_owned()).collect());
@@ -234,7 +244,7 @@ impl TraitorEventPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.event_prefab.identifier
     }
 }
@@ -479,7 +489,7 @@ impl FromStr for LevelType {
 
 #[derive(Debug)]
 pub struct EventSprite {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub sprite: BarotraumaSprite,
 }
 
@@ -487,14 +497,14 @@ impl EventSprite {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let sprite = BarotraumaSprite::new(element);
 
         Self { identifier, sprite }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -502,14 +512,14 @@ impl EventSprite {
 #[derive(Debug)]
 
 pub struct EventSet {
-    pub identifier: String,
-    pub biome_identifier: Option<String>,
+    pub identifier: Identifier,
+    pub biome_identifier: Option<Identifier>,
     pub min_level_difficulty: f32,
     pub max_level_difficulty: f32,
     pub additive: bool,
     pub level_type: level_generation_parameters_file::LevelType,
     pub faction: Option<String>,
-    pub location_type_identifiers: Option<Vec<String>>,
+    pub location_type_identifiers: Option<Vec<Identifier>>,
     pub min_intensity: f32,
     pub max_intensity: f32,
     pub choose_random: bool,
@@ -544,17 +554,17 @@ impl EventSet {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
-            .unwrap_or(
+            .map(Identifier::from)
+            .unwrap_or(Identifier::new(
                 thread_rng()
                     .sample_iter(&Alphanumeric)
                     .take(10)
                     .map(char::from)
-                    .collect(),
-            ); //TODO: Complex identifier based on where the file is located
+                    .collect::<String>(),
+            )); //TODO: Complex identifier based on where the file is located
         let biome_identifier = element
             .attribute_ignore_ascii_case("biome")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let min_level_difficulty = element
             .attribute_ignore_ascii_case("minleveldifficulty")
             .map_or(0.0, |v| v.parse::<f32>().unwrap());
@@ -575,7 +585,7 @@ impl EventSet {
             .map(|v| v.to_owned());
         let location_type_identifiers = element
             .attribute_ignore_ascii_case("locationtype")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let min_intensity = element
             .attribute_ignore_ascii_case("minintensity")
             .map_or(0.0, |v| v.parse::<f32>().unwrap());
@@ -703,7 +713,7 @@ impl EventSet {
                     {
                         let identifiers = child
                             .attribute_ignore_ascii_case("identifier")
-                            .map(|v| v.split(',').map(|v| v.to_string()).collect::<Vec<_>>())
+                            .map(Identifier::split_list)
                             .unwrap();
                         let commonness = child
                             .attribute_ignore_ascii_case("commonness")
@@ -719,7 +729,10 @@ impl EventSet {
                             faction_id,
                         });
                     } else {
-                        let prefab = EventPrefab::new(child, Some(format!("{}-{}", identifier, i)));
+                        let prefab = EventPrefab::new(
+                            child,
+                            Some(Identifier::new(format!("{}-{}", identifier, i))),
+                        );
                         event_prefabs.push(SubEventPrefab {
                             faction_id: prefab.faction.clone(),
                             commonness: Some(prefab.commonness),
@@ -771,7 +784,7 @@ impl EventSet {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -787,5 +800,5 @@ pub struct SubEventPrefab {
 #[derive(Debug)]
 pub enum PrefabOrIdentifiers {
     Prefab(EventPrefab),
-    Identifiers(Vec<String>),
+    Identifiers(Vec<Identifier>),
 }
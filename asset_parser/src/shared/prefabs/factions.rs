@@ -1,12 +1,15 @@
 use roxmltree::Node;
 
-use crate::shared::{content_files::level_generation_parameters_file::LevelType, util::NodeExp};
+use crate::shared::{
+    content_files::level_generation_parameters_file::LevelType, identifier::Identifier,
+    util::NodeExp,
+};
 
 use super::item_prefab::{BarotraumaSprite, Color};
 
 #[derive(Debug)]
 pub struct FactionPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub menu_order: u32,
     pub start_outpost: bool,
     pub min_reputation: i32,
@@ -29,7 +32,7 @@ impl FactionPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let menu_order = element
             .attribute_ignore_ascii_case("menuorder")
@@ -123,15 +126,15 @@ impl FactionPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
 #[derive(Debug)]
 pub struct HireableCharacter {
-    pub npc_set_identifier: String,
-    pub npc_identifier: String,
+    pub npc_set_identifier: Identifier,
+    pub npc_identifier: Identifier,
     pub min_reputation: f32,
 }
 impl HireableCharacter {
@@ -139,12 +142,12 @@ impl HireableCharacter {
         let npc_set_identifier = element
             .attribute_ignore_ascii_case("from")
             .or(element.attribute_ignore_ascii_case("npcsetidentifier"))
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let npc_identifier = element
             .attribute_ignore_ascii_case("identifier")
             .or(element.attribute_ignore_ascii_case("npcidentifier"))
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let min_reputation = element
             .attribute_ignore_ascii_case("minreputation")
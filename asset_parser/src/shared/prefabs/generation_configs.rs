@@ -2,15 +2,18 @@ use std::collections::HashSet;
 
 use roxmltree::Node;
 
-use crate::shared::{content_files::level_generation_parameters_file::LevelType, util::NodeExp};
+use crate::shared::{
+    content_files::level_generation_parameters_file::LevelType, identifier::Identifier,
+    util::NodeExp,
+};
 
 use super::human_prefab::HumanPrefab;
 
 #[derive(Debug)]
 pub struct OutpostGenerationParams {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub name: Option<String>,
-    pub allowed_location_types: Option<HashSet<String>>,
+    pub allowed_location_types: Option<HashSet<Identifier>>,
     pub force_to_end_location_index: i32,
     pub preferred_difficulty: i32,
     pub total_module_count: u32,
@@ -36,14 +39,18 @@ impl OutpostGenerationParams {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let name = element
             .attribute_ignore_ascii_case("name")
             .map(|v| v.to_owned());
         let allowed_location_types = element
             .attribute_ignore_ascii_case("allowedlocationtypes")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<HashSet<_>>());
+            .map(|v| {
+                Identifier::split_list(v)
+                    .into_iter()
+                    .collect::<HashSet<_>>()
+            });
         let force_to_end_location_index = element
             .attribute_ignore_ascii_case("forcetoendlocationindex")
             .map_or(-1, |v| v.parse::<i32>().unwrap());
@@ -108,7 +115,7 @@ impl OutpostGenerationParams {
                     for child in child.children().filter(Node::is_element) {
                         let from = child
                             .attribute_ignore_ascii_case("from")
-                            .map(|v| v.to_owned())
+                            .map(Identifier::from)
                             .unwrap();
 
                         npcs.push(HumanPrefab::new(child, Some(from)))
@@ -145,14 +152,14 @@ impl OutpostGenerationParams {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
 #[derive(Debug)]
 pub struct ModuleCount {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub count: u32,
     pub order: u32,
     pub required_faction: Option<String>,
@@ -163,7 +170,7 @@ impl ModuleCount {
         let identifier = element
             .attribute_ignore_ascii_case("flag")
             .or(element.attribute_ignore_ascii_case("moduletype"))
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let count = element
             .attribute_ignore_ascii_case("count")
@@ -200,7 +207,7 @@ impl RuinGenerationParams {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.outpost_generation_params.identifier
     }
 }
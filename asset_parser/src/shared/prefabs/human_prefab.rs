@@ -3,25 +3,25 @@ use std::str::FromStr;
 use bitfield_struct::bitfield;
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::item_prefab::{Color, DoesNotExistError};
 
 #[derive(Debug)]
 pub struct HumanPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub human_properties: HumanProperties,
     pub itemsets: Vec<(ItemSet, f32)>,
     pub custom_character_infos: Vec<(CharacterInfo, f32)>,
     pub preferred_outpost_module_types: Vec<String>,
-    pub npc_set_identifier: Option<String>,
+    pub npc_set_identifier: Option<Identifier>,
 }
 
 impl HumanPrefab {
-    pub fn new(element: Node, npc_set_identifier: Option<String>) -> Self {
+    pub fn new(element: Node, npc_set_identifier: Option<Identifier>) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let human_properties = HumanProperties::new(element);
         let mut itemsets = Vec::new();
@@ -92,7 +92,7 @@ pub struct CharacterInfo {
     pub salary: Option<u32>,
     pub experience_points: Option<u32>,
     pub additional_talent_points: Option<u32>,
-    pub tags: Option<Vec<String>>,
+    pub tags: Option<Vec<Identifier>>,
     pub species_name: Option<String>,
     pub hair_index: Option<u32>,
     pub beard_index: Option<u32>,
@@ -104,8 +104,8 @@ pub struct CharacterInfo {
     pub start_items_given: bool,
     pub personality_name: Option<String>,
     pub ragdoll_file_name: Option<String>,
-    pub npc_set_id: Option<String>,
-    pub npc_id: Option<String>,
+    pub npc_set_id: Option<Identifier>,
+    pub npc_id: Option<Identifier>,
     pub missions_completed_since_death: Option<u32>,
     pub min_reputation_to_hire: Option<(String, f32)>,
     pub job: Option<Job>,
@@ -130,11 +130,9 @@ impl CharacterInfo {
         let additional_talent_points = element
             .attribute_ignore_ascii_case("additionaltalentpoints")
             .map(|v| v.parse::<u32>().unwrap());
-        let tags = element.attribute_ignore_ascii_case("tags").map(|v| {
-            v.split(',')
-                .map(std::borrow::ToOwned::to_owned)
-                .collect::<Vec<_>>()
-        });
+        let tags = element
+            .attribute_ignore_ascii_case("tags")
+            .map(Identifier::split_list);
         let species_name = element
             .attribute_ignore_ascii_case("speciesname")
             .map(std::borrow::ToOwned::to_owned);
@@ -170,10 +168,10 @@ impl CharacterInfo {
             .map(std::borrow::ToOwned::to_owned);
         let npc_set_id = element
             .attribute_ignore_ascii_case("npcsetid")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let npc_id = element
             .attribute_ignore_ascii_case("npcid")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let missions_completed_since_death = element
             .attribute_ignore_ascii_case("missionscompletedsincedeath")
             .map(|v| v.parse::<u32>().unwrap());
@@ -203,7 +201,7 @@ impl CharacterInfo {
                             .unwrap();
                         let stat_identifier = child
                             .attribute_ignore_ascii_case("statidentifier")
-                            .map(std::borrow::ToOwned::to_owned)
+                            .map(Identifier::from)
                             .unwrap();
                         let remove_on_death = child
                             .attribute_ignore_ascii_case("removeondeath")
@@ -270,7 +268,7 @@ impl CharacterInfo {
 
 #[derive(Debug)]
 pub struct Job {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub skills: Vec<Skill>,
 }
 
@@ -278,7 +276,7 @@ impl Job {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(std::borrow::ToOwned::to_owned)
+            .map(Identifier::from)
             .unwrap();
         let skills = element
             .children()
@@ -287,7 +285,7 @@ impl Job {
             .map(|child| {
                 let skill_identifier = child
                     .attribute_ignore_ascii_case("identifier")
-                    .map(std::borrow::ToOwned::to_owned)
+                    .map(Identifier::from)
                     .unwrap();
                 let level = child
                     .attribute_ignore_ascii_case("level")
@@ -306,7 +304,7 @@ impl Job {
 
 #[derive(Debug)]
 pub struct Skill {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub level: f32,
 }
 
@@ -676,7 +674,7 @@ impl FromStr for StatType {
 pub struct StatValue {
     pub stat_type: StatType,
     pub value: f32,
-    pub identifier: String,
+    pub identifier: Identifier,
     pub remove_on_death: bool,
 }
 
@@ -684,9 +682,9 @@ pub struct StatValue {
 
 pub struct Item {
     pub amount: u32,
-    pub identifier: String,
+    pub identifier: Identifier,
     pub equip: bool,
-    pub tags: Vec<String>,
+    pub tags: Vec<Identifier>,
     pub inner_items: Vec<Item>,
 }
 
@@ -697,18 +695,14 @@ impl Item {
             .map_or(1, |v| v.parse::<u32>().unwrap());
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let equip = element
             .attribute_ignore_ascii_case("equip")
             .is_some_and(|v| v.parse::<bool>().unwrap());
         let tags = element
             .attribute_ignore_ascii_case("tags")
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            })
+            .map(Identifier::split_list)
             .unwrap_or_default();
         let inner_items = element
             .children()
@@ -727,14 +721,14 @@ impl Item {
 
 #[derive(Debug)]
 pub struct HumanProperties {
-    pub job: String,
+    pub job: Identifier,
     pub commonness: f32,
     pub health_multiplier: f32,
     pub health_multiplier_in_multiplayer: f32,
     pub aim_speed: f32,
     pub aim_accuracy: f32,
     pub experience_points: u32,
-    pub tags: Vec<String>,
+    pub tags: Vec<Identifier>,
     pub module_flags: Vec<String>,
     pub spawn_point_tags: Vec<String>,
     pub campaign_interaction_type: InteractionType,
@@ -772,7 +766,7 @@ impl HumanProperties {
                 .map_or(0, |v| v.parse().unwrap()),
             tags: element
                 .attribute_ignore_ascii_case("tags")
-                .map(|v| v.split(',').map(std::borrow::ToOwned::to_owned).collect())
+                .map(Identifier::split_list)
                 .unwrap_or_default(),
             module_flags: element
                 .attribute_ignore_ascii_case("moduleflags")
@@ -886,7 +880,7 @@ impl CorpsePrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.human_prefab.identifier
     }
 }
@@ -3,18 +3,18 @@ use std::num::ParseIntError;
 use glam::Vec2;
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::{item_prefab::MapEntityProperties, map_generation_params::Point};
 
 #[derive(Debug)]
 pub struct ItemAssemblyPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub map_entity_properties: MapEntityProperties,
     pub name: Option<String>,
     pub description: Option<String>,
     pub contained_item_ids: Vec<u32>,
-    pub display_entities: Vec<(String, Rect)>,
+    pub display_entities: Vec<(Identifier, Rect)>,
     pub bounds: Option<Rect>,
 }
 
@@ -26,8 +26,8 @@ impl ItemAssemblyPrefab {
             .map(std::borrow::ToOwned::to_owned);
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
-            .or(name.clone())
+            .or(name.as_deref())
+            .map(Identifier::from)
             .unwrap();
         let description = element
             .attribute_ignore_ascii_case("description")
@@ -71,8 +71,7 @@ impl ItemAssemblyPrefab {
             }
             let identifier = child
                 .attribute_ignore_ascii_case("identifier")
-                .map(std::borrow::ToOwned::to_owned)
-                .unwrap_or(child.tag_name().name().to_lowercase());
+                .map_or(Identifier::from(child.tag_name().name()), Identifier::from);
             let rect = child.attribute_ignore_ascii_case("rect").map_or(
                 Rect {
                     x: 0i32,
@@ -140,12 +139,12 @@ impl ItemAssemblyPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rect {
     pub x: i32,
     pub y: i32,
@@ -7,6 +7,7 @@ use log::warn;
 use roxmltree::Node;
 
 use crate::shared::{
+    identifier::Identifier,
     submarine_info::{ParseVectorError, Vector2},
     util::NodeExp,
 };
@@ -162,7 +163,7 @@ pub struct ItemProperties {
     pub sonar_size: f32,
     pub use_in_health_interface: bool,
     pub disable_item_usage_when_selected: bool,
-    pub cargo_container_identifier: String,
+    pub cargo_container_identifier: Identifier,
     pub use_contained_sprite_color: bool,
     pub use_contained_inventory_icon_color: bool,
     pub added_repair_speed_multiplier: f32,
@@ -277,8 +278,7 @@ impl ItemProperties {
                 .is_some_and(|v| v.parse().unwrap()),
             cargo_container_identifier: element
                 .attribute_ignore_ascii_case("cargocontaineridentifier")
-                .unwrap_or("metalcrate")
-                .to_owned(),
+                .map_or(Identifier::from("metalcrate"), Identifier::from),
             use_contained_sprite_color: element
                 .attribute_ignore_ascii_case("usecontainedspritecolor")
                 .is_some_and(|v| v.parse().unwrap()),
@@ -503,24 +503,24 @@ const NEUTRAL_DENSITY: f32 = 10.0;
 
 #[derive(Debug)]
 pub struct ItemPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub name: Option<String>,
-    pub variant_of: Option<String>,
+    pub variant_of: Option<Identifier>,
     pub category: MapEntityCategory,
-    pub name_identifier: Option<String>,
-    pub fallback_name_identifier: Option<String>,
-    pub description_identifier: Option<String>,
-    pub aliases: Option<Vec<String>>,
+    pub name_identifier: Option<Identifier>,
+    pub fallback_name_identifier: Option<Identifier>,
+    pub description_identifier: Option<Identifier>,
+    pub aliases: Option<Vec<Identifier>>,
     pub allow_as_extra_cargo: Option<bool>,
-    pub tags: Option<Vec<String>>,
+    pub tags: Option<Vec<Identifier>>,
     pub item_properties: ItemProperties,
     pub map_entity_properties: MapEntityProperties,
-    pub allow_dropping_on_swap_with: Option<Vec<String>>,
-    pub allowed_links: Option<Vec<String>>,
+    pub allow_dropping_on_swap_with: Option<Vec<Identifier>>,
+    pub allowed_links: Option<Vec<Identifier>>,
     pub skill_requirement_hints: Vec<SkillRequirementHint>,
     pub sprite: Option<ItemSprite>,
     pub default_price: Option<DefaultPriceInfo>,
-    pub store_prices: HashMap<String, PriceInfo>,
+    pub store_prices: HashMap<Identifier, PriceInfo>,
     pub deconstruct_time: Option<f32>,
     pub allow_deconstruct: bool,
     pub random_deconstruction_output: bool,
@@ -537,20 +537,22 @@ pub struct ItemPrefab {
     pub suitable_treatments: HashMap<String, f32>,
     pub static_body: Option<ItemStaticBody>,
     pub body: Option<ItemBody>,
+    /// XML of the element the prefab was read from, the elements of variants get merged into the one of their base
+    pub source: Box<str>,
 }
 
 impl ItemPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let name = element
             .attribute_ignore_ascii_case("name")
             .map(std::borrow::ToOwned::to_owned);
         let variant_of = element
             .attribute_ignore_ascii_case("inherit")
             .or(element.attribute_ignore_ascii_case("variantof"))
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
 
         let category = element.attribute_ignore_ascii_case("category").map_or(
             MapEntityCategory::new().with_misc(true),
@@ -568,16 +570,12 @@ impl ItemPrefab {
 
         let fallback_name_identifier = element
             .attribute_ignore_ascii_case("fallbacknameidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
 
         let aliases = element
             .attribute_ignore_ascii_case("aliases")
             .or(element.attribute_ignore_ascii_case("Aliases"))
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<String>>()
-            });
+            .map(Identifier::split_list);
 
         let allow_as_extra_cargo = element
             .attribute_ignore_ascii_case("allowasextracargo")
@@ -586,38 +584,26 @@ impl ItemPrefab {
         let tags = element
             .attribute_ignore_ascii_case("tags")
             .or(element.attribute_ignore_ascii_case("Tags"))
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<String>>()
-            });
+            .map(Identifier::split_list);
 
         let mut item_properties = ItemProperties::new(element);
         let map_entity_properties = MapEntityProperties::new(element);
 
         let description_identifier = element
             .attribute_ignore_ascii_case("descriptionidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let name_identifier = element
             .attribute_ignore_ascii_case("nameidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
 
         let allow_dropping_on_swap_with = element
             .attribute_ignore_ascii_case("allowdroppingonswapwith")
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            });
+            .map(Identifier::split_list);
         item_properties.allow_dropping_on_swap = allow_dropping_on_swap_with.is_some();
 
         let allowed_links = element
             .attribute_ignore_ascii_case("allowedlinks")
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            });
+            .map(Identifier::split_list);
 
         let mut skill_requirement_hints = Vec::new();
         let mut sprite = None;
@@ -873,10 +859,11 @@ impl ItemPrefab {
             suitable_treatments,
             static_body,
             body,
+            source: element.document().input_text()[element.range()].into(),
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -950,11 +937,11 @@ pub struct Trigger {
 #[derive(Debug)]
 pub struct SwappableItem {
     pub base_price: Option<u32>,
-    pub swap_identifier: Option<String>,
+    pub swap_identifier: Option<Identifier>,
     pub can_be_bought: bool,
     pub replacement_on_uninstall: Option<String>,
     pub swap_origin: Option<Vec2>,
-    pub spawn_with_id: Option<String>,
+    pub spawn_with_id: Option<Identifier>,
     pub schematic_sprite: Option<BarotraumaSprite>,
     pub connected_items_to_swap: Vec<SwapConnectedItem>,
 }
@@ -966,7 +953,7 @@ impl SwappableItem {
             .map(|v| v.parse::<u32>().unwrap());
         let swap_identifier = element
             .attribute_ignore_ascii_case("swapidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let can_be_bought = element
             .attribute_ignore_ascii_case("canbebought")
             .map_or(base_price.is_some_and(|v| v != 0), |v| {
@@ -980,7 +967,7 @@ impl SwappableItem {
             .map(|v| v.parse::<Vector2>().unwrap());
         let spawn_with_id = element
             .attribute_ignore_ascii_case("spawnwithid")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
 
         let mut schematic_sprite = None;
         let mut connected_items_to_swap = Vec::new();
@@ -1126,7 +1113,7 @@ impl PreferredContainer {
 #[derive(Debug)]
 pub struct FabricationRecipe {
     pub display_name: Option<String>,
-    pub suitable_fabricators: Option<Vec<String>>,
+    pub suitable_fabricators: Option<Vec<Identifier>>,
     pub required_time: f32,
     pub required_money: u32,
     pub out_condition: f32,
@@ -1148,11 +1135,7 @@ impl FabricationRecipe {
             .map(std::borrow::ToOwned::to_owned);
         let suitable_fabricators = element
             .attribute_ignore_ascii_case("suitablefabricators")
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            });
+            .map(Identifier::split_list);
         let required_time = element
             .attribute_ignore_ascii_case("requiredtime")
             .map_or(1.0, |v| v.parse::<f32>().unwrap());
@@ -1191,8 +1174,8 @@ impl FabricationRecipe {
                     "requiredskill" => required_skills.push(SkillRequirement {
                         identifier: child
                             .attribute_ignore_ascii_case("identifier")
-                            .unwrap()
-                            .to_owned(),
+                            .map(Identifier::from)
+                            .unwrap(),
                         level: child
                             .attribute_ignore_ascii_case("level")
                             .unwrap()
@@ -1223,7 +1206,7 @@ impl FabricationRecipe {
                             .map(std::borrow::ToOwned::to_owned);
                         if let Some(identifier) = identifier {
                             required_items.push(RequiredItem::ByIdentifier {
-                                identifier: identifier.to_owned(),
+                                identifier: identifier.into(),
                                 min_condition,
                                 max_condition,
                                 use_condition,
@@ -1233,7 +1216,7 @@ impl FabricationRecipe {
                             });
                         } else if let Some(tag) = tag {
                             required_items.push(RequiredItem::ByTag {
-                                tag: tag.to_owned(),
+                                tag: tag.into(),
                                 min_condition,
                                 max_condition,
                                 use_condition,
@@ -1275,7 +1258,7 @@ impl FabricationRecipe {
 #[derive(Debug)]
 pub enum RequiredItem {
     ByIdentifier {
-        identifier: String,
+        identifier: Identifier,
         min_condition: Option<f32>,
         max_condition: Option<f32>,
         use_condition: bool,
@@ -1284,7 +1267,7 @@ pub enum RequiredItem {
         header: Option<String>,
     },
     ByTag {
-        tag: String,
+        tag: Identifier,
         min_condition: Option<f32>,
         max_condition: Option<f32>,
         use_condition: bool,
@@ -1294,15 +1277,31 @@ pub enum RequiredItem {
     },
 }
 
+impl RequiredItem {
+    pub fn amount(&self) -> u32 {
+        match self {
+            Self::ByIdentifier { amount, .. } | Self::ByTag { amount, .. } => *amount,
+        }
+    }
+
+    /// Item identifier or tag the requirement matches
+    pub fn target(&self) -> &Identifier {
+        match self {
+            Self::ByIdentifier { identifier, .. } => identifier,
+            Self::ByTag { tag, .. } => tag,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SkillRequirement {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub level: u32,
 }
 
 #[derive(Debug)]
 pub struct DeconstructItem {
-    pub item_identifier: String,
+    pub item_identifier: Identifier,
     pub amount: u32,
     pub min_condition: Option<f32>,
     pub max_condition: Option<f32>,
@@ -1310,8 +1309,8 @@ pub struct DeconstructItem {
     pub out_condition_max: Option<f32>,
     pub copy_condition: bool,
     pub commonness: f32,
-    pub required_deconstructor: Option<Vec<String>>,
-    pub required_other_item: Option<Vec<String>>,
+    pub required_deconstructor: Option<Vec<Identifier>>,
+    pub required_other_item: Option<Vec<Identifier>>,
     pub activate_button_text: Option<String>,
     pub info_text: Option<String>,
     pub info_text_on_other_item_missing: Option<String>,
@@ -1321,8 +1320,8 @@ impl DeconstructItem {
     pub fn new(element: Node) -> Self {
         let item_identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let amount = element
             .attribute_ignore_ascii_case("amount")
             .map_or(1, |v| v.parse::<u32>().unwrap());
@@ -1351,18 +1350,10 @@ impl DeconstructItem {
             .or(element
                 .parent_element()
                 .and_then(|parent| parent.attribute_ignore_ascii_case("requireddeconstructor")))
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            });
+            .map(Identifier::split_list);
         let required_other_item = element
             .attribute_ignore_ascii_case("requiredotheritem")
-            .map(|v| {
-                v.split(',')
-                    .map(std::borrow::ToOwned::to_owned)
-                    .collect::<Vec<_>>()
-            });
+            .map(Identifier::split_list);
         let activate_button_text = element
             .attribute_ignore_ascii_case("activatebuttontext")
             .map(std::borrow::ToOwned::to_owned);
@@ -1468,8 +1459,8 @@ impl PriceInfos {
                     .map_or(buying_price_multiplier, |v| v.parse::<f32>().unwrap());
                 let store_identifier = child
                     .attribute_ignore_ascii_case("storeidentifier")
-                    .unwrap()
-                    .to_owned();
+                    .map(Identifier::from)
+                    .unwrap();
                 let min_reputations = child
                     .children()
                     .filter(Node::is_element)
@@ -1546,7 +1537,7 @@ pub struct PriceInfo {
     pub min_level_difficulty: u32,
     pub can_be_special: bool,
     pub display_non_empty: bool,
-    pub store_identifier: String,
+    pub store_identifier: Identifier,
     pub requires_unlock: bool,
     pub min_reputations: HashMap<String, f32>,
 }
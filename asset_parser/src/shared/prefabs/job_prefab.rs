@@ -3,7 +3,7 @@ use std::ops::RangeInclusive;
 use glam::Vec2;
 use roxmltree::Node;
 
-use crate::shared::{submarine_info::Vector2, util::NodeExp};
+use crate::shared::{identifier::Identifier, submarine_info::Vector2, util::NodeExp};
 
 use super::{
     human_prefab::BehaviorType,
@@ -12,7 +12,7 @@ use super::{
 
 #[derive(Debug)]
 pub struct ItemRepairPriority {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub priority: f32,
 }
 
@@ -20,7 +20,7 @@ impl ItemRepairPriority {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("tag")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let priority = element
             .attribute_ignore_ascii_case("priority")
@@ -33,14 +33,14 @@ impl ItemRepairPriority {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
 #[derive(Debug)]
 pub struct JobPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub ui_color: Color,
     pub idle_behavior: BehaviorType,
     pub only_job_specific_dialog: bool,
@@ -55,7 +55,7 @@ pub struct JobPrefab {
     pub item_sets: Vec<JobItemSet>,
     pub skills: Vec<SkillPrefab>,
     pub autonomous_objectives: Vec<AutonomousObjective>,
-    pub appropriate_orders: Vec<String>,
+    pub appropriate_orders: Vec<Identifier>,
     pub icon: Option<BarotraumaSprite>,
     pub icon_small: Option<BarotraumaSprite>,
 }
@@ -64,7 +64,7 @@ impl JobPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let ui_color = element.attribute_ignore_ascii_case("uicolor").map_or(
             Color::Simple {
@@ -155,7 +155,7 @@ impl JobPrefab {
                         |child| {
                             child
                                 .attribute_ignore_ascii_case("identifier")
-                                .map(|v| v.to_owned())
+                                .map(Identifier::from)
                                 .unwrap()
                         },
                     ));
@@ -196,14 +196,14 @@ impl JobPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
 #[derive(Debug)]
 pub struct AutonomousObjective {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub option: Option<String>,
     pub priority_modifier: f32,
     pub ignore_at_outpost: bool,
@@ -213,7 +213,7 @@ impl AutonomousObjective {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let option = element
             .attribute_ignore_ascii_case("option")
@@ -236,7 +236,7 @@ impl AutonomousObjective {
 
 #[derive(Debug)]
 pub struct SkillPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub price_modifier: f32,
     pub level_range: RangeInclusive<f32>,
     pub is_primary_skill: bool,
@@ -246,7 +246,7 @@ impl SkillPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let price_modifier = element
             .attribute_ignore_ascii_case("pricemodifier")
@@ -280,7 +280,7 @@ impl SkillPrefab {
 
 pub struct JobItem {
     pub name: Option<String>,
-    pub identifier: Option<String>,
+    pub identifier: Option<Identifier>,
     pub equip: bool,
     pub child_items: Vec<JobItem>,
 }
@@ -292,7 +292,7 @@ impl JobItem {
             .map(|v| v.to_owned());
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         assert!(name.is_some() || identifier.is_some());
         let equip = element
             .attribute_ignore_ascii_case("equip")
@@ -4,7 +4,7 @@ use bitfield_struct::bitfield;
 use glam::Vec2;
 use roxmltree::Node;
 
-use crate::shared::{submarine_info::Vector2, util::NodeExp};
+use crate::shared::{identifier::Identifier, submarine_info::Vector2, util::NodeExp};
 
 use super::{
     event_prefab::EventPrefab,
@@ -14,7 +14,7 @@ use super::{
 
 #[derive(Debug)]
 pub struct LevelObjectPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub properties: LevelObjectPrefabProperties,
     pub sprites: Vec<(BarotraumaSprite, Option<PhysicsBody>)>,
     pub deformable_sprite: Option<DeformableSprite>,
@@ -28,7 +28,7 @@ impl LevelObjectPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap_or_default();
         let properties = LevelObjectPrefabProperties::new(element);
 
@@ -46,7 +46,7 @@ impl LevelObjectPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 
@@ -172,7 +172,7 @@ pub struct TriggerPropertiesOverrides {
 pub struct LevelTrigger {
     pub physics_body: Option<PhysicsBodyWithIsSensor>,
     pub camera_shake: Option<f32>,
-    pub infect_identifier: Option<String>,
+    pub infect_identifier: Option<Identifier>,
     pub infection_chance: Option<f32>,
     pub trigger_once: bool,
     pub stay_triggered_delay: Option<f32>,
@@ -188,7 +188,7 @@ pub struct LevelTrigger {
     pub force_mode: Option<TriggerForceMode>,
     pub triggered_by: Option<TriggererType>,
     pub trigger_others_distance: Option<f32>,
-    pub tags: Option<Vec<String>>,
+    pub tags: Option<Vec<Identifier>>,
     pub allowed_other_trigger_tags: Option<Vec<String>>,
     pub status_effects: Vec<StatusEffect>,
     pub attacks: Vec<Attack>,
@@ -210,7 +210,7 @@ impl LevelTrigger {
             .map(|v| v.parse::<f32>().unwrap());
         let infect_identifier = element
             .attribute_ignore_ascii_case("infectidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let infection_chance = element
             .attribute_ignore_ascii_case("infectionchance")
             .map(|v| v.parse::<f32>().unwrap());
@@ -269,7 +269,7 @@ impl LevelTrigger {
             .map(|v| v.parse::<f32>().unwrap());
         let tags = element
             .attribute_ignore_ascii_case("tags")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let allowed_other_trigger_tags = if triggered_by.is_some_and(|v| v.other_trigger()) {
             element
                 .attribute_ignore_ascii_case("allowedothertriggertags")
@@ -379,7 +379,7 @@ impl DelayedStatusEffect {
 
 #[derive(Debug)]
 pub struct NormalStatusEffect {
-    pub tags: Option<Vec<String>>,
+    pub tags: Option<Vec<Identifier>>,
     pub only_inside: bool,
     pub only_outside: bool,
     pub only_when_damaged_by_player: bool,
@@ -398,7 +398,7 @@ pub struct NormalStatusEffect {
     pub target_limbs: Option<Vec<LimbType>>,
     pub sever_limbs_probability: Option<f32>,
     pub target_types: Option<TargetType>,
-    pub target_identifiers: Option<Vec<String>>,
+    pub target_identifiers: Option<Vec<Identifier>>,
     pub triggered_event_target_tag: Option<String>,
     pub triggered_event_entity_tag: Option<String>,
     pub triggered_event_user_tag: Option<String>,
@@ -419,7 +419,7 @@ pub struct NormalStatusEffect {
     pub property_conditionals: Vec<PropertyConditional>,
     pub required_items: Vec<RelatedItem>,
     pub spawn_items: Vec<ItemSpawnInfo>,
-    pub triggered_events_identifiers: Vec<String>,
+    pub triggered_events_identifiers: Vec<Identifier>,
     pub scripted_triggered_events: Vec<EventPrefab>,
     pub spawn_characters: Vec<CharacterSpawnInfo>,
     pub give_talent_infos: Vec<GiveTalentInfo>,
@@ -441,7 +441,7 @@ impl NormalStatusEffect {
     pub fn new(element: Node) -> Self {
         let tags = element
             .attribute_ignore_ascii_case("tags")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let only_inside = element
             .attribute_ignore_ascii_case("onlyinside")
             .map_or(false, |v| v.to_lowercase().parse().unwrap());
@@ -515,7 +515,7 @@ impl NormalStatusEffect {
             .or(element.attribute_ignore_ascii_case("targets"))
             .or(element.attribute_ignore_ascii_case("targetidentifiers"))
             .or(element.attribute_ignore_ascii_case("targettags"))
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let triggered_event_target_tag = element
             .attribute_ignore_ascii_case("eventtargettag")
             .map(|v| v.to_owned());
@@ -641,7 +641,7 @@ impl NormalStatusEffect {
                     } else {
                         let affliction_identifier = child
                             .attribute_ignore_ascii_case("identifier")
-                            .map(|v| v.to_owned())
+                            .map(Identifier::from)
                             .unwrap();
                         let strength = child
                             .attribute_ignore_ascii_case("amount")
@@ -691,7 +691,7 @@ impl NormalStatusEffect {
                 "triggerevent" => {
                     let identifier = child
                         .attribute_ignore_ascii_case("identifier")
-                        .map(|v| v.to_owned());
+                        .map(Identifier::from);
                     if let Some(identifier) = identifier {
                         triggered_events_identifiers.push(identifier);
                     }
@@ -2631,8 +2631,8 @@ impl PhysicsBodyWithIsSensor {
 #[derive(Debug)]
 
 pub struct RelatedItem {
-    pub identifiers: Option<Vec<String>>,
-    pub excluded_identifiers: Option<Vec<String>>,
+    pub identifiers: Option<Vec<Identifier>>,
+    pub excluded_identifiers: Option<Vec<Identifier>>,
     pub exclude_broken: bool,
     pub require_empty: bool,
     pub exclude_full_condition: bool,
@@ -2664,7 +2664,7 @@ impl RelatedItem {
                 .or(element.attribute_ignore_ascii_case("tags"))
                 .or(element.attribute_ignore_ascii_case("identifier"))
                 .or(element.attribute_ignore_ascii_case("tag"))
-                .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>())
+                .map(Identifier::split_list)
         };
         let excluded_identifiers = element
             .attribute_ignore_ascii_case("excludeditems")
@@ -2673,7 +2673,7 @@ impl RelatedItem {
             .or(element.attribute_ignore_ascii_case("excludedtags"))
             .or(element.attribute_ignore_ascii_case("excludedidentifier"))
             .or(element.attribute_ignore_ascii_case("excludedtag"))
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let exclude_broken = element
             .attribute_ignore_ascii_case("excludebroken")
             .map_or(true, |v| v.parse().unwrap());
@@ -2822,7 +2822,7 @@ impl FromStr for RelationType {
 
 #[derive(Debug)]
 pub struct ItemSpawnInfo {
-    pub item_identifier: String,
+    pub item_identifier: Identifier,
     pub spawn_if_inventory_full: bool,
     pub spawn_if_not_in_inventory: bool,
     pub spawn_if_cant_be_contained: bool,
@@ -2847,7 +2847,7 @@ impl ItemSpawnInfo {
             element
                 .attribute_ignore_ascii_case("identifier")
                 .or(element.attribute_ignore_ascii_case("identifiers"))
-                .map(|v| v.to_owned())
+                .map(Identifier::from)
                 .unwrap()
         };
         let spawn_if_inventory_full = element
@@ -3034,7 +3034,7 @@ impl CharacterSpawnInfo {
 
 #[derive(Debug)]
 pub struct GiveTalentInfo {
-    pub talent_identifiers: Vec<String>,
+    pub talent_identifiers: Vec<Identifier>,
     pub give_random: bool,
 }
 
@@ -3043,7 +3043,7 @@ impl GiveTalentInfo {
         Self {
             talent_identifiers: element
                 .attribute_ignore_ascii_case("talentidentifiers")
-                .map(|v| v.split(',').map(|v| v.to_owned()).collect())
+                .map(Identifier::split_list)
                 .unwrap(),
             give_random: element
                 .attribute_ignore_ascii_case("giverandom")
@@ -3054,7 +3054,7 @@ impl GiveTalentInfo {
 
 #[derive(Debug)]
 pub struct GiveSkill {
-    pub skill_identifier: String,
+    pub skill_identifier: Identifier,
     pub amount: f32,
     pub trigger_talents: bool,
 }
@@ -3064,7 +3064,7 @@ impl GiveSkill {
         Self {
             skill_identifier: element
                 .attribute_ignore_ascii_case("skillidentifier")
-                .map(|v| v.to_owned())
+                .map(Identifier::from)
                 .unwrap(),
             amount: element
                 .attribute_ignore_ascii_case("amount")
@@ -3155,7 +3155,7 @@ impl FromStr for AIState {
 
 #[derive(Debug)]
 pub struct GiveAffliction {
-    pub affliction_identifier: String,
+    pub affliction_identifier: Identifier,
     pub strength: f32,
     pub probability: f32,
 }
@@ -3199,7 +3199,7 @@ impl FromStr for SoundSelectionMode {
 #[derive(Debug)]
 pub struct AfflictionProperties {
     pub strength: f32,
-    pub identifier: String,
+    pub identifier: Identifier,
     pub probability: f32,
     pub divide_by_limb_count: bool,
     pub multiply_by_max_vitality: bool,
@@ -3213,7 +3213,7 @@ impl AfflictionProperties {
                 .map_or(0.0, |v| v.parse().unwrap()),
             identifier: element
                 .attribute_ignore_ascii_case("identifier")
-                .map(|v| v.to_owned())
+                .map(Identifier::from)
                 .unwrap(),
             probability: element
                 .attribute_ignore_ascii_case("probability")
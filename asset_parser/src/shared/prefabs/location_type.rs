@@ -2,7 +2,7 @@ use std::{collections::HashMap, str::FromStr};
 
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::{
     item_prefab::{BarotraumaSprite, Color, DoesNotExistError},
@@ -11,7 +11,7 @@ use super::{
 
 #[derive(Debug)]
 pub struct LocationType {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub beacon_station_chance: f32,
     pub use_portrait_in_random_loading_screens: bool,
     pub has_outpost: bool,
@@ -21,16 +21,16 @@ pub struct LocationType {
     pub faction: Option<String>,
     pub secondary_faction: Option<String>,
     pub show_sonar_marker: bool,
-    pub mission_identifiers: Option<Vec<String>>,
+    pub mission_identifiers: Option<Vec<Identifier>>,
     pub mission_tags: Option<Vec<String>>,
     pub hide_entity_subcategories: Option<Vec<String>>,
     pub replace_in_radiation: Option<String>,
-    pub force_outpost_generation_params_identifier: Option<String>,
+    pub force_outpost_generation_params_identifier: Option<Identifier>,
     pub ignore_generic_events: bool,
     pub outpost_team: CharacterTeamType,
     pub force_location_name: Option<String>,
     pub name_files: Option<Vec<String>>,
-    pub name_identifiers: Option<Vec<String>>,
+    pub name_identifiers: Option<Vec<Identifier>>,
     pub commonness_per_zone: Option<HashMap<u32, f32>>,
     pub min_count_per_zone: Option<HashMap<u32, u32>>,
     pub hireable_jobs: Vec<(String, f32)>,
@@ -54,7 +54,7 @@ impl LocationType {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let beacon_station_chance = element
             .attribute_ignore_ascii_case("beaconstationchance")
@@ -85,7 +85,7 @@ impl LocationType {
             .map_or(true, |v| v.parse().unwrap());
         let mission_identifiers = element
             .attribute_ignore_ascii_case("missionidentifiers")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let mission_tags = element
             .attribute_ignore_ascii_case("missiontags")
             .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
@@ -97,7 +97,7 @@ impl LocationType {
             .map(|v| v.to_owned());
         let force_outpost_generation_params_identifier = element
             .attribute_ignore_ascii_case("forceoutpostgenerationparamsidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let ignore_generic_events = element
             .attribute_ignore_ascii_case("ignoregenericevents")
             .map_or(false, |v| v.parse().unwrap());
@@ -119,9 +119,7 @@ impl LocationType {
                     Some(
                         element
                             .attribute_ignore_ascii_case("nameidentifiers")
-                            .map_or(vec![identifier.clone()], |v| {
-                                v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>()
-                            }),
+                            .map_or(vec![identifier.clone()], Identifier::split_list),
                     ),
                 )
             };
@@ -202,7 +200,7 @@ impl LocationType {
                     radiation_sprite = Some(BarotraumaSprite::new(child));
                 }
                 "changeto" => {
-                    can_change_to.push(LocationTypeChange::new(child, &identifier));
+                    can_change_to.push(LocationTypeChange::new(child, identifier.as_str()));
                 }
                 "portrait" => {
                     portraits.push(BarotraumaSprite::new(child));
@@ -278,7 +276,7 @@ impl LocationType {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
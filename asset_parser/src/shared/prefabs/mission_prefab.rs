@@ -4,6 +4,7 @@ use bitfield_struct::bitfield;
 use roxmltree::Node;
 
 use crate::shared::{
+    identifier::Identifier,
     prefabs::{
         event_prefab::SingleMissionType,
         item_prefab::{BarotraumaSprite, Color},
@@ -21,9 +22,9 @@ use super::{
 
 #[derive(Debug)]
 pub struct MissionPrefab {
-    pub identifier: String,
-    pub text_identifier: String,
-    pub tags: Option<Vec<String>>,
+    pub identifier: Identifier,
+    pub text_identifier: Identifier,
+    pub tags: Option<Vec<Identifier>>,
     pub name: Option<String>,
     pub description: Option<String>,
     pub reward: u32,
@@ -45,21 +46,21 @@ pub struct MissionPrefab {
     pub success_message_tag: Option<String>,
     pub failure_message_tag: Option<String>,
     pub sonar_label_tag: Option<String>,
-    pub sonar_icon_identifier: Option<String>,
+    pub sonar_icon_identifier: Option<Identifier>,
     pub multiplayer_only: bool,
     pub singleplayer_only: bool,
-    pub achievement_identifier: Option<String>,
+    pub achievement_identifier: Option<Identifier>,
     pub unhide_entity_sub_categories: Option<Vec<String>>,
     pub display_target_hud_icons: bool,
     pub hud_icon_max_distance: f32,
     pub mission_type_specific_properties: MissionTypeSpecific,
 
     pub messages: Vec<Message>,
-    pub allowed_location_types: Vec<String>,
+    pub allowed_location_types: Vec<Identifier>,
     pub allowed_connection_types: Vec<(String, String)>,
     pub location_type_change_on_completed: Option<LocationTypeChange>,
     pub reputation_rewards: Vec<ReputationReward>,
-    pub data_rewards: Vec<(String, SetDataActionConvert, OperationType)>,
+    pub data_rewards: Vec<(Identifier, SetDataActionConvert, OperationType)>,
     pub trigger_events: Vec<TriggerEvent>,
     pub icon: Option<BarotraumaSprite>,
     pub icon_color: Option<Color>,
@@ -76,14 +77,14 @@ impl MissionPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let text_identifier = element
             .attribute_ignore_ascii_case("textidentifier")
-            .map_or(identifier.clone(), |v| v.to_owned());
+            .map_or(identifier.clone(), Identifier::from);
         let tags = element
             .attribute_ignore_ascii_case("tags")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let name = element
             .attribute_ignore_ascii_case("name")
             .map(|v| v.to_owned());
@@ -151,7 +152,7 @@ impl MissionPrefab {
             .map(|v| v.to_owned());
         let sonar_icon_identifier = element
             .attribute_ignore_ascii_case("sonaricon")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let multiplayer_only = element
             .attribute_ignore_ascii_case("multiplayeronly")
             .map_or(false, |v| v.parse().unwrap());
@@ -160,7 +161,7 @@ impl MissionPrefab {
             .map_or(false, |v| v.parse().unwrap());
         let achievement_identifier = element
             .attribute_ignore_ascii_case("achievementidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let unhide_entity_sub_categories = element
             .attribute_ignore_ascii_case("unhideentitysubcategories")
             .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
@@ -215,7 +216,7 @@ impl MissionPrefab {
                 "locationtype" | "connectiontype" => {
                     if let Some(identifier) = child
                         .attribute_ignore_ascii_case("identifier")
-                        .map(|v| v.to_owned())
+                        .map(Identifier::from)
                     {
                         allowed_location_types.push(identifier);
                     } else {
@@ -246,7 +247,7 @@ impl MissionPrefab {
                 "metadata" => {
                     let identifier = child
                         .attribute_ignore_ascii_case("identifier")
-                        .map(|v| v.to_owned())
+                        .map(Identifier::from)
                         .unwrap();
                     let value = child
                         .attribute_ignore_ascii_case("value")
@@ -380,7 +381,7 @@ impl MissionPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -1239,7 +1240,7 @@ pub struct EndMission {
     pub minion_species_name: String,
     pub minion_count: u32,
     pub minion_scatter: f32,
-    pub projectile_identifier: String,
+    pub projectile_identifier: Identifier,
     pub spawn_point_tag: String,
     pub destructible_item_tag: String,
     pub end_cinematic_sound: String,
@@ -1264,7 +1265,7 @@ impl EndMission {
             .map_or(0.0, |v| v.parse::<f32>().unwrap().min(10000.0));
         let projectile_identifier = element
             .attribute_ignore_ascii_case("projectile")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let spawn_point_tag = element
             .attribute_ignore_ascii_case("spawnPointTag")
@@ -1331,8 +1332,8 @@ impl ScanMission {
 }
 #[derive(Debug)]
 pub struct EliminateTargetsMission {
-    pub target_item_identifiers: Option<Vec<String>>,
-    pub target_enemy_identifiers: Option<Vec<String>>,
+    pub target_item_identifiers: Option<Vec<Identifier>>,
+    pub target_enemy_identifiers: Option<Vec<Identifier>>,
     pub min_enemy_count: u32,
     pub target_sub_type: SubmarineType,
     pub mission: Mission,
@@ -1341,10 +1342,10 @@ impl EliminateTargetsMission {
     pub fn new(element: Node) -> Self {
         let target_item_identifiers = element
             .attribute_ignore_ascii_case("targetitems")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let target_enemy_identifiers = element
             .attribute_ignore_ascii_case("targetenemies")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let min_enemy_count = element
             .attribute_ignore_ascii_case("minenemycount")
             .map_or(0, |v| v.parse::<u32>().unwrap());
@@ -1383,7 +1384,7 @@ impl Mission {
 }
 #[derive(Debug)]
 pub struct CheckDataAction {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub condition: String,
     pub force_string: bool,
     pub check_against_metadata: bool,
@@ -1394,7 +1395,7 @@ impl CheckDataAction {
         let binary_option_action = BinaryOptionAction::new(element);
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let condition = element
             .attribute_ignore_ascii_case("condition")
@@ -1482,7 +1483,7 @@ impl SubactionGroup {
 pub struct PirateCharacterTypeVariant {
     pub preferred_difficulty: f32,
     pub is_commander: bool,
-    pub character_identifier: String,
+    pub character_identifier: Identifier,
     pub character_from: String,
 }
 impl PirateCharacterTypeVariant {
@@ -1495,7 +1496,7 @@ impl PirateCharacterTypeVariant {
             .map_or(false, |v| v.parse().unwrap());
         let character_identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let character_from = element
             .attribute_ignore_ascii_case("from")
@@ -1515,7 +1516,7 @@ impl PirateCharacterTypeVariant {
 pub struct PirateSubmarineConfig {
     pub preferred_difficulty: f32,
     pub alternate_reward: Option<f32>,
-    pub faction_identifier: Option<String>,
+    pub faction_identifier: Option<Identifier>,
     pub path: String,
 }
 impl PirateSubmarineConfig {
@@ -1528,7 +1529,7 @@ impl PirateSubmarineConfig {
             .map(|v| v.parse::<f32>().unwrap());
         let faction_identifier = element
             .attribute_ignore_ascii_case("faction")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let path = element
             .attribute_ignore_ascii_case("path")
             .map(|v| v.to_owned())
@@ -1544,9 +1545,9 @@ impl PirateSubmarineConfig {
 
 #[derive(Debug)]
 pub struct EscortMissionCharacter {
-    pub escort_identifier: Option<String>,
+    pub escort_identifier: Option<Identifier>,
     pub color: Color,
-    pub character_identifier: String,
+    pub character_identifier: Identifier,
     pub character_from: String,
     pub status_effects: Vec<StatusEffect>,
 }
@@ -1554,7 +1555,7 @@ impl EscortMissionCharacter {
     pub fn new(element: Node) -> Self {
         let escort_identifier = element
             .attribute_ignore_ascii_case("escortidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let color = element.attribute_ignore_ascii_case("color").map_or(
             Color::Simple {
                 r: 1.0,
@@ -1566,7 +1567,7 @@ impl EscortMissionCharacter {
         );
         let character_identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let character_from = element
             .attribute_ignore_ascii_case("from")
@@ -1625,7 +1626,7 @@ pub enum InvalidMineralSpawnPosition {
 
 #[derive(Debug)]
 pub struct AbandonedOutpostHuman {
-    pub character_identifier: String,
+    pub character_identifier: Identifier,
     pub character_from: String,
     pub module_flags: Option<Vec<String>>,
     pub spawn_point_tags: Option<Vec<String>>,
@@ -1642,7 +1643,7 @@ impl AbandonedOutpostHuman {
     pub fn new(element: Node) -> Self {
         let character_identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let character_from = element
             .attribute_ignore_ascii_case("from")
@@ -1761,7 +1762,7 @@ impl AbandonedOutpostMonster {
 }
 #[derive(Debug)]
 pub struct AbandonedOutpostItem {
-    pub item_identifier: String,
+    pub item_identifier: Identifier,
     pub module_flags: Option<Vec<String>>,
     pub spawn_point_tags: Option<Vec<String>>,
     pub as_far_as_possible: bool,
@@ -1770,7 +1771,7 @@ impl AbandonedOutpostItem {
     pub fn new(element: Node) -> Self {
         let item_identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let module_flags = element
             .attribute_ignore_ascii_case("moduleflags")
@@ -1821,14 +1822,14 @@ impl NestMonsterPrefab {
 }
 #[derive(Debug)]
 pub struct NestItem {
-    pub item_identifier: String,
+    pub item_identifier: Identifier,
     pub status_effect_on_approach: Option<StatusEffect>,
 }
 impl NestItem {
     pub fn new(element: Node) -> Self {
         let item_identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let status_effect_on_approach = element
             .children()
@@ -1887,7 +1888,7 @@ pub struct Message {
 
 #[derive(Debug)]
 pub struct ReputationReward {
-    pub faction_identifier: String,
+    pub faction_identifier: Identifier,
     pub amount: f32,
     pub amount_for_opposing_faction: f32,
 }
@@ -1896,7 +1897,7 @@ impl ReputationReward {
     pub fn new(element: Node) -> Self {
         let faction_identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let amount = element
             .attribute_ignore_ascii_case("amount")
@@ -1954,8 +1955,8 @@ impl FromStr for OperationType {
 
 #[derive(Debug)]
 pub struct TriggerEvent {
-    pub event_identifier: Option<String>,
-    pub event_tag: Option<String>,
+    pub event_identifier: Option<Identifier>,
+    pub event_tag: Option<Identifier>,
     pub state: u32,
     pub delay: f32,
     pub campaign_only: bool,
@@ -1965,10 +1966,10 @@ impl TriggerEvent {
         Self {
             event_identifier: element
                 .attribute_ignore_ascii_case("eventidentifier")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             event_tag: element
                 .attribute_ignore_ascii_case("eventtag")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             state: element
                 .attribute_ignore_ascii_case("state")
                 .map_or(0, |v| v.parse().unwrap()),
@@ -5,6 +5,7 @@ pub mod cave_generation_params;
 pub mod character_prefab;
 pub mod cpr_settings_prefab;
 pub mod decal_prefab;
+pub mod event_action;
 pub mod event_manager_settings;
 pub mod event_prefab;
 pub mod factions;
@@ -1,10 +1,10 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 #[derive(Debug)]
 pub struct NPCPersonalityTrait {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub name: Option<String>,
     pub allowed_dialog_tags: Vec<String>,
     pub commonness: f32,
@@ -17,8 +17,8 @@ impl NPCPersonalityTrait {
             .map(|v| v.to_owned());
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
-            .or(name.clone())
+            .or(name.as_deref())
+            .map(Identifier::from)
             .unwrap();
         let allowed_dialog_tags = element
             .attribute_ignore_ascii_case("alloweddialogtags")
@@ -36,7 +36,7 @@ impl NPCPersonalityTrait {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
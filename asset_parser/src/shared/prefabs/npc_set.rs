@@ -1,12 +1,12 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::human_prefab::HumanPrefab;
 
 #[derive(Debug)]
 pub struct NPCSet {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub humans: Vec<HumanPrefab>,
 }
 
@@ -14,8 +14,8 @@ impl NPCSet {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let humans = element
             .children()
             .filter(Node::is_element)
@@ -25,7 +25,7 @@ impl NPCSet {
         Self { identifier, humans }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -2,13 +2,13 @@ use std::str::FromStr;
 
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::item_prefab::{BarotraumaSprite, Color, DoesNotExistError};
 
 #[derive(Debug)]
 pub struct OrderCategoryIcon {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub category: OrderCategory,
     pub sprite: BarotraumaSprite,
     pub color: Color,
@@ -18,9 +18,9 @@ impl OrderCategoryIcon {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("category")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
-        let category = identifier.parse().unwrap();
+        let category = identifier.as_str().parse().unwrap();
         let sprite = BarotraumaSprite::new(
             element
                 .children()
@@ -46,7 +46,7 @@ impl OrderCategoryIcon {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -76,7 +76,7 @@ impl FromStr for OrderCategory {
 
 #[derive(Debug)]
 pub struct OrderPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub target_item_type: Option<String>,
     pub can_type_be_subclass: bool,
     pub color: Option<Color>,
@@ -89,9 +89,9 @@ pub struct OrderPrefab {
     pub preferred_jobs: Option<Vec<String>>,
     pub options: Option<Vec<String>>,
     pub hidden_options: Option<Vec<String>>,
-    pub option_target_items: Option<Vec<Vec<String>>>,
-    pub target_items: Option<Vec<String>>,
-    pub require_items: Option<Vec<String>>,
+    pub option_target_items: Option<Vec<Vec<Identifier>>>,
+    pub target_items: Option<Vec<Identifier>>,
+    pub require_items: Option<Vec<Identifier>>,
     pub category: Option<OrderCategory>,
     pub must_set_target: bool,
     pub can_be_generalized: bool,
@@ -111,7 +111,7 @@ impl OrderPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let target_item_type = element
             .attribute_ignore_ascii_case("targetitemtype")
@@ -156,22 +156,19 @@ impl OrderPrefab {
             if s.contains(';') {
                 let mut option_target_items_v = Vec::new();
                 let mut all_target_items = Vec::new();
-                for v in s
-                    .split(';')
-                    .map(|s| s.split(',').map(|v| v.to_owned()).collect::<Vec<_>>())
-                {
+                for v in s.split(';').map(Identifier::split_list) {
                     all_target_items.extend_from_slice(&v);
                     option_target_items_v.push(v);
                 }
                 target_items = Some(all_target_items);
                 option_target_items = Some(option_target_items_v);
             } else {
-                target_items = Some(s.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+                target_items = Some(Identifier::split_list(s));
             }
         };
         let require_items = element
             .attribute_ignore_ascii_case("requireitems")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>());
+            .map(Identifier::split_list);
         let category = element
             .attribute_ignore_ascii_case("category")
             .map(|v| v.parse::<OrderCategory>().unwrap());
@@ -270,7 +267,7 @@ impl OrderPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
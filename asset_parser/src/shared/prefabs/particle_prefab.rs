@@ -3,7 +3,7 @@ use std::str::FromStr;
 use glam::Vec2;
 use roxmltree::Node;
 
-use crate::shared::{submarine_info::Vector2, util::NodeExp};
+use crate::shared::{identifier::Identifier, submarine_info::Vector2, util::NodeExp};
 
 use super::{
     gui_style_prefabs::SpriteSheet,
@@ -13,7 +13,7 @@ use super::{
 
 #[derive(Debug)]
 pub struct ParticlePrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub life_time: f32,
     pub life_time_min: f32,
     pub start_delay_min: f32,
@@ -56,7 +56,7 @@ pub struct ParticlePrefab {
 
 impl ParticlePrefab {
     pub fn new(element: Node) -> Self {
-        let identifier = element.tag_name().name().to_lowercase();
+        let identifier = Identifier::from(element.tag_name().name());
         let life_time = element
             .attribute_ignore_ascii_case("lifetime")
             .map_or(5.0, |v| v.parse::<f32>().unwrap());
@@ -270,7 +270,7 @@ impl ParticlePrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
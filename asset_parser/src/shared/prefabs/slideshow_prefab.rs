@@ -1,12 +1,12 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::item_prefab::BarotraumaSprite;
 
 #[derive(Debug)]
 pub struct SlideshowPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub slides: Vec<Slide>,
 }
 
@@ -14,8 +14,8 @@ impl SlideshowPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let slides = element
             .children()
             .filter(Node::is_element)
@@ -26,7 +26,7 @@ impl SlideshowPrefab {
         Self { identifier, slides }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -4,15 +4,15 @@ use glam::Vec2;
 use roxmltree::Node;
 
 use crate::shared::{
-    content_files::level_generation_parameters_file::SourcePoolIndex, submarine_info::Vector2,
-    util::NodeExp,
+    content_files::level_generation_parameters_file::SourcePoolIndex, identifier::Identifier,
+    submarine_info::Vector2, util::NodeExp,
 };
 
 use super::item_prefab::DoesNotExistError;
 
 #[derive(Debug)]
 pub struct SoundPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub sound_path: String,
     pub volume: f32,
     pub range: f32,
@@ -31,11 +31,12 @@ impl SoundPrefab {
                 .attribute_ignore_ascii_case("path")
                 .map(|v| v.rsplit_once('.').map(|v| v.0).unwrap_or(v)))
             .map_or(
-                sound_path
-                    .rsplit_once('.')
-                    .map(|v| v.0.to_owned())
-                    .unwrap_or(sound_path.clone()),
-                |v| v.to_owned(),
+                Identifier::from(
+                    sound_path
+                        .rsplit_once('.')
+                        .map_or(sound_path.as_str(), |v| v.0),
+                ),
+                Identifier::from,
             );
 
         let volume = element
@@ -57,7 +58,7 @@ impl SoundPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -90,7 +91,7 @@ impl DamageSoundPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.sound_prefab.identifier
     }
 }
@@ -136,7 +137,7 @@ impl BackgroundMusicPrefabs {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.sound_prefab.identifier
     }
 }
@@ -157,7 +158,7 @@ impl GUISoundPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.sound_prefab.identifier
     }
 }
@@ -1,10 +1,10 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 #[derive(Debug)]
 pub struct StartItemSet {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub items: Vec<StartItem>,
     pub order: i32,
 }
@@ -13,7 +13,7 @@ impl StartItemSet {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let items = element
             .children()
@@ -32,14 +32,14 @@ impl StartItemSet {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
 #[derive(Debug)]
 pub struct StartItem {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub amount: u32,
     pub multi_player_only: bool,
 }
@@ -48,8 +48,8 @@ impl StartItem {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let amount = element
             .attribute_ignore_ascii_case("amount")
             .map_or(1, |v| v.parse::<u32>().unwrap());
@@ -7,7 +7,7 @@ use bitfield_struct::bitfield;
 use glam::Vec2;
 use roxmltree::Node;
 
-use crate::shared::{submarine_info::Vector2, util::NodeExp};
+use crate::shared::{identifier::Identifier, submarine_info::Vector2, util::NodeExp};
 
 use super::{
     item_prefab::{
@@ -18,11 +18,11 @@ use super::{
 
 #[derive(Debug)]
 pub struct StructurePrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub original_name: Option<String>,
-    pub name_identifier: Option<String>,
-    pub fallback_name_identifier: Option<String>,
-    pub tags: HashSet<String>,
+    pub name_identifier: Option<Identifier>,
+    pub fallback_name_identifier: Option<Identifier>,
+    pub tags: HashSet<Identifier>,
     pub is_horizontal: bool,
     pub sprite: BarotraumaSprite,
     pub sprite_effects: SpriteEffects,
@@ -34,15 +34,15 @@ pub struct StructurePrefab {
     pub decorative_sprite_groups: HashMap<u32, Option<DecorativeSprite>>,
     pub properties: StructurePrefabProperties,
     pub category: MapEntityCategory,
-    pub aliases: Vec<String>,
-    pub description_identifier: Option<String>,
+    pub aliases: Vec<Identifier>,
+    pub description_identifier: Option<Identifier>,
 }
 
 impl StructurePrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let original_name = element
             .attribute_ignore_ascii_case("name")
@@ -50,14 +50,14 @@ impl StructurePrefab {
         //TODO: ConfigElement
         let name_identifier = element
             .attribute_ignore_ascii_case("nameidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let fallback_name_identifier = element
             .attribute_ignore_ascii_case("fallbacknameidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let mut tags = element
             .attribute_ignore_ascii_case("tags")
             .map_or(Default::default(), |v| {
-                v.split(',').map(|v| v.to_owned()).collect::<HashSet<_>>()
+                HashSet::from_iter(Identifier::split_list(v))
             });
         let is_horizontal = element
             .attribute_ignore_ascii_case("fallbacknameidentifier")
@@ -167,22 +167,21 @@ impl StructurePrefab {
         let aliases = {
             let mut v = element
                 .attribute_ignore_ascii_case("aliases")
-                .map_or(Vec::new(), |v| {
-                    v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>()
-                });
+                .map_or(Vec::new(), Identifier::split_list);
 
-            let non_translated_name = element
-                .attribute_ignore_ascii_case("name")
-                .map_or(element.tag_name().name().to_owned(), |v| v.to_owned());
+            let non_translated_name = element.attribute_ignore_ascii_case("name").map_or(
+                Identifier::from(element.tag_name().name()),
+                Identifier::from,
+            );
             v.push(non_translated_name);
             v
         };
         if properties.body {
-            tags.insert("wall".to_owned());
+            tags.insert(Identifier::from("wall"));
         }
         let description_identifier = element
             .attribute_ignore_ascii_case("descriptionidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         if !element.has_attribute_ignore_ascii_case("size") {
             //backwards compatibility
             if !element.has_attribute_ignore_ascii_case("width")
@@ -224,7 +223,7 @@ impl StructurePrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
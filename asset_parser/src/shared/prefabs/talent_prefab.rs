@@ -1,14 +1,14 @@
 use roxmltree::Node;
 
-use crate::shared::{util::NodeExp, version::Version};
+use crate::shared::{identifier::Identifier, util::NodeExp, version::Version};
 
 use super::item_prefab::{BarotraumaSprite, Color};
 
 #[derive(Debug)]
 pub struct TalentPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub ability_effects_stack_with_same_talent: bool,
-    pub name_identifier: Option<String>,
+    pub name_identifier: Option<Identifier>,
     pub color_override: Option<Color>,
     pub migrations: Vec<TalentMigration>,
     pub icon: Option<BarotraumaSprite>,
@@ -19,14 +19,14 @@ impl TalentPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let ability_effects_stack_with_same_talent = element
             .attribute_ignore_ascii_case("abilityeffectsstackwithsametalent")
             .map_or(true, |v| v.parse().unwrap());
         let name_identifier = element
             .attribute_ignore_ascii_case("nameidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let color_override = element
             .attribute_ignore_ascii_case("coloroverride")
             .map(|v| v.parse::<Color>().unwrap());
@@ -92,7 +92,7 @@ impl TalentPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -2,13 +2,13 @@ use std::{collections::HashMap, str::FromStr};
 
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::item_prefab::DoesNotExistError;
 
 #[derive(Debug)]
 pub struct TalentTree {
-    pub job_identifier: String,
+    pub job_identifier: Identifier,
     pub sub_trees: Vec<TalentSubTree>,
 }
 
@@ -16,8 +16,8 @@ impl TalentTree {
     pub fn new(element: Node) -> Self {
         let job_identifier = element
             .attribute_ignore_ascii_case("jobidentifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let sub_trees = element
             .children()
             .filter(Node::is_element)
@@ -30,18 +30,18 @@ impl TalentTree {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.job_identifier
     }
 }
 
 #[derive(Debug)]
 pub struct TalentSubTree {
-    pub identifier: String,
-    pub name_identifier: Option<String>,
+    pub identifier: Identifier,
+    pub name_identifier: Option<Identifier>,
     pub tree_type: TalentTreeType,
-    pub required_trees: Option<Vec<String>>,
-    pub blocked_trees: Option<Vec<String>>,
+    pub required_trees: Option<Vec<Identifier>>,
+    pub blocked_trees: Option<Vec<Identifier>>,
     pub talent_option_stages: Vec<TalentOption>,
 }
 
@@ -49,26 +49,22 @@ impl TalentSubTree {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .unwrap()
-            .to_owned();
+            .map(Identifier::from)
+            .unwrap();
         let name_identifier = element
             .attribute_ignore_ascii_case("nameidentifier")
-            .map(std::borrow::ToOwned::to_owned);
+            .map(Identifier::from);
         let tree_type = element
             .attribute_ignore_ascii_case("type")
             .map_or(TalentTreeType::Specialization, |v| {
                 v.parse::<TalentTreeType>().unwrap()
             });
-        let required_trees = element.attribute_ignore_ascii_case("requires").map(|v| {
-            v.split(',')
-                .map(std::borrow::ToOwned::to_owned)
-                .collect::<Vec<_>>()
-        });
-        let blocked_trees = element.attribute_ignore_ascii_case("blocks").map(|v| {
-            v.split(',')
-                .map(std::borrow::ToOwned::to_owned)
-                .collect::<Vec<_>>()
-        });
+        let required_trees = element
+            .attribute_ignore_ascii_case("requires")
+            .map(Identifier::split_list);
+        let blocked_trees = element
+            .attribute_ignore_ascii_case("blocks")
+            .map(Identifier::split_list);
         let talent_option_stages = element
             .children()
             .filter(Node::is_element)
@@ -91,8 +87,8 @@ impl TalentSubTree {
 pub struct TalentOption {
     pub max_chosen_talents: u32,
     pub required_talents: u32,
-    pub identifiers: Vec<String>,
-    pub show_case_talents: HashMap<String, Vec<String>>,
+    pub identifiers: Vec<Identifier>,
+    pub show_case_talents: HashMap<Identifier, Vec<Identifier>>,
 }
 
 impl TalentOption {
@@ -112,21 +108,21 @@ impl TalentOption {
                     identifiers.push(
                         child
                             .attribute_ignore_ascii_case("identifier")
-                            .unwrap()
-                            .to_owned(),
+                            .map(Identifier::from)
+                            .unwrap(),
                     );
                 }
                 "showcasetalent" => {
                     let show_case_identifier = child
                         .attribute_ignore_ascii_case("identifier")
-                        .unwrap()
-                        .to_owned();
+                        .map(Identifier::from)
+                        .unwrap();
                     let mut show_case_talent_identifiers = Vec::new();
                     for child in child.children().filter(Node::is_element) {
                         let identifier = child
                             .attribute_ignore_ascii_case("identifier")
-                            .unwrap()
-                            .to_owned();
+                            .map(Identifier::from)
+                            .unwrap();
                         show_case_talent_identifiers.push(identifier.clone());
                         identifiers.push(identifier);
                     }
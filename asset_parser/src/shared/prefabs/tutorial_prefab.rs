@@ -2,13 +2,13 @@ use std::str::FromStr;
 
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 use super::item_prefab::{BarotraumaSprite, DoesNotExistError};
 
 #[derive(Debug)]
 pub struct TutorialPrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub order: Option<u32>,
     pub disable_bot_conversations: bool,
     pub allow_character_switch: bool,
@@ -18,15 +18,15 @@ pub struct TutorialPrefab {
     pub level_params: String,
     pub tutorial_character: Option<TutorialCharacter>,
     pub banner: Option<BarotraumaSprite>,
-    pub event_identifier: String,
+    pub event_identifier: Identifier,
     pub end_type: EndType,
-    pub next_tutorial_identifier: Option<String>,
+    pub next_tutorial_identifier: Option<Identifier>,
 }
 impl TutorialPrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let order = element
             .attribute_ignore_ascii_case("order")
@@ -80,7 +80,7 @@ impl TutorialPrefab {
             })
             .unwrap()
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let end_message_element = element
             .children()
@@ -92,7 +92,7 @@ impl TutorialPrefab {
             .map_or(EndType::None, |v| v.parse().unwrap());
         let next_tutorial_identifier = end_message_element
             .attribute_ignore_ascii_case("nexttutorial")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
 
         Self {
             identifier,
@@ -111,7 +111,7 @@ impl TutorialPrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -139,7 +139,7 @@ impl FromStr for EndType {
 pub struct TutorialCharacter {
     pub starting_item_tags: Vec<String>,
     pub species_name: String,
-    pub job_prefab_identifier: String,
+    pub job_prefab_identifier: Identifier,
     pub job_variant: u32,
     pub skills: Vec<(String, f32)>,
 }
@@ -155,7 +155,7 @@ impl TutorialCharacter {
             .map_or("human".to_owned(), |v| v.to_owned());
         let job_prefab_identifier = element
             .attribute_ignore_ascii_case("jobidentifier")
-            .map_or("assistant".to_owned(), |v| v.to_owned());
+            .map_or(Identifier::from("assistant"), Identifier::from);
         let job_variant = element
             .attribute_ignore_ascii_case("variant")
             .map_or(0, |v| v.parse::<u32>().unwrap());
@@ -7,24 +7,24 @@ use std::{
 
 use roxmltree::Node;
 
-use crate::shared::{submarine_info::SubmarineClass, util::NodeExp};
+use crate::shared::{identifier::Identifier, submarine_info::SubmarineClass, util::NodeExp};
 
 use super::{item_prefab::BarotraumaSprite, structure_prefab::DecorativeSprite};
 
 #[derive(Debug)]
 pub struct UpgradeCategory {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub self_item_tags: Option<Vec<String>>,
     pub name: Option<String>,
     pub is_wall_upgrade: bool,
-    pub name_identifier: Option<String>,
+    pub name_identifier: Option<Identifier>,
 }
 
 impl UpgradeCategory {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let self_item_tags = element
             .attribute_ignore_ascii_case("items")
@@ -37,7 +37,7 @@ impl UpgradeCategory {
             .map_or(false, |v| v.parse().unwrap());
         let name_identifier = element
             .attribute_ignore_ascii_case("nameidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
 
         Self {
             identifier,
@@ -48,23 +48,23 @@ impl UpgradeCategory {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
 
 #[derive(Debug)]
 pub struct UpgradePrefab {
-    pub identifier: String,
+    pub identifier: Identifier,
     pub name: Option<String>,
     pub description: Option<String>,
     pub max_level: u32,
     pub suppress_warnings: bool,
     pub hide_in_menus: bool,
-    pub name_identifier: Option<String>,
-    pub description_identifier: Option<String>,
+    pub name_identifier: Option<Identifier>,
+    pub description_identifier: Option<Identifier>,
     pub increase_on_tooltip: Option<f32>,
-    pub upgrade_category_identifiers: HashSet<String>,
+    pub upgrade_category_identifiers: HashSet<Identifier>,
     pub price: Option<UpgradePrice>,
     pub max_levels: Vec<UpgradeMaxLevelMod>,
     pub resource_costs: Vec<UpgradeResourceCost>,
@@ -77,7 +77,7 @@ impl UpgradePrefab {
     pub fn new(element: Node) -> Self {
         let identifier = element
             .attribute_ignore_ascii_case("identifier")
-            .map(|v| v.to_owned())
+            .map(Identifier::from)
             .unwrap();
         let name = element
             .attribute_ignore_ascii_case("name")
@@ -96,16 +96,20 @@ impl UpgradePrefab {
             .map_or(false, |v| v.parse().unwrap());
         let name_identifier = element
             .attribute_ignore_ascii_case("nameidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let description_identifier = element
             .attribute_ignore_ascii_case("descriptionidentifier")
-            .map(|v| v.to_owned());
+            .map(Identifier::from);
         let increase_on_tooltip = element
             .attribute_ignore_ascii_case("increaseontooltip")
             .map(|v| v.parse::<f32>().unwrap());
         let upgrade_category_identifiers = element
             .attribute_ignore_ascii_case("categories")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<HashSet<_>>())
+            .map(|v| {
+                Identifier::split_list(v)
+                    .into_iter()
+                    .collect::<HashSet<_>>()
+            })
             .unwrap();
 
         let mut price = None;
@@ -161,7 +165,7 @@ impl UpgradePrefab {
         }
     }
 
-    pub fn get_identifier(&self) -> &str {
+    pub fn get_identifier(&self) -> &Identifier {
         &self.identifier
     }
 }
@@ -254,7 +258,7 @@ pub enum MaxLevelModType {
 #[derive(Debug)]
 pub struct UpgradeResourceCost {
     pub amount: u32,
-    pub target_items: Vec<String>,
+    pub target_items: Vec<Identifier>,
     pub target_levels: RangeInclusive<u32>,
 }
 
@@ -266,7 +270,7 @@ impl UpgradeResourceCost {
             .unwrap();
         let target_items = element
             .attribute_ignore_ascii_case("item")
-            .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>())
+            .map(Identifier::split_list)
             .unwrap();
         let target_levels = element
             .attribute_ignore_ascii_case("levels")
@@ -1,17 +1,17 @@
 use roxmltree::Node;
 
-use crate::shared::util::NodeExp;
+use crate::shared::{identifier::Identifier, util::NodeExp};
 
 #[derive(Debug)]
 pub struct WreckAIConfig {
-    pub identifier: String,
-    pub defensive_agent: Option<String>,
-    pub offensive_agent: Option<String>,
-    pub brain: Option<String>,
-    pub spawner: Option<String>,
-    pub brain_room_background: Option<String>,
-    pub brain_room_vertical_wall: Option<String>,
-    pub brain_room_horizontal_wall: Option<String>,
+    pub identifier: Identifier,
+    pub defensive_agent: Option<Identifier>,
+    pub offensive_agent: Option<Identifier>,
+    pub brain: Option<Identifier>,
+    pub spawner: Option<Identifier>,
+    pub brain_room_background: Option<Identifier>,
+    pub brain_room_vertical_wall: Option<Identifier>,
+    pub brain_room_horizontal_wall: Option<Identifier>,
     pub agent_spawn_delay: f32,
     pub agent_spawn_delay_random_factor: f32,
     pub agent_spawn_delay_difficulty_multiplier: f32,
@@ -27,7 +27,7 @@ pub struct WreckAIConfig {
     pub kill_agents_when_entity_dies: bool,
     pub dead_entity_color_multiplier: f32,
     pub dead_entity_color_fade_out_time: f32,
-    pub forbidden_ammunition: Vec<String>,
+    pub forbidden_ammunition: Vec<Identifier>,
 }
 
 impl WreckAIConfig {
@@ -35,29 +35,29 @@ impl WreckAIConfig {
         Self {
             identifier: element
                 .attribute_ignore_ascii_case("Entity")
-                .map(|v| v.to_owned())
+                .map(Identifier::from)
                 .unwrap(),
             defensive_agent: element
                 .attribute_ignore_ascii_case("defensiveagent")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             offensive_agent: element
                 .attribute_ignore_ascii_case("offensiveagent")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             brain: element
                 .attribute_ignore_ascii_case("brain")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             spawner: element
                 .attribute_ignore_ascii_case("spawner")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             brain_room_background: element
                 .attribute_ignore_ascii_case("brainroombackground")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             brain_room_vertical_wall: element
                 .attribute_ignore_ascii_case("brainroomverticalwall")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             brain_room_horizontal_wall: element
                 .attribute_ignore_ascii_case("brainroomhorizontalwall")
-                .map(|v| v.to_owned()),
+                .map(Identifier::from),
             agent_spawn_delay: element
                 .attribute_ignore_ascii_case("agentspawndelay")
                 .map_or(60.0, |v| v.parse().unwrap()),
@@ -105,7 +105,7 @@ impl WreckAIConfig {
                 .map_or(1.0, |v| v.parse().unwrap()),
             forbidden_ammunition: element
                 .attribute_ignore_ascii_case("forbiddenammunition")
-                .map(|v| v.split(',').map(|v| v.to_owned()).collect())
+                .map(Identifier::split_list)
                 .unwrap(),
         }
     }
@@ -9,6 +9,7 @@ use glam::Vec2;
 use roxmltree::Node;
 
 use crate::shared::{
+    identifier::Identifier,
     prefabs::{item_assembly_prefab::Rect, item_prefab::Color},
     util::NodeExp,
 };
@@ -240,8 +241,8 @@ impl SubmarineInfo {
                         .to_owned();
                     let identifier = child
                         .attribute_ignore_ascii_case("identifier")
-                        .unwrap()
-                        .to_owned();
+                        .map(Identifier::from)
+                        .unwrap();
                     let rect = child
                         .attribute_ignore_ascii_case("rect")
                         .map(|v| Rect::from_str(v, false).unwrap())
@@ -403,8 +404,8 @@ impl SubmarineInfo {
                         .to_owned();
                     let identifier = child
                         .attribute_ignore_ascii_case("identifier")
-                        .unwrap()
-                        .to_owned();
+                        .map(Identifier::from)
+                        .unwrap();
                     let pending_swap = child
                         .attribute_ignore_ascii_case("pendingswap")
                         .map(|v| v.to_owned());
@@ -500,7 +501,7 @@ impl SubmarineInfo {
                                             .unwrap(),
                                         talent_identifier: child
                                             .attribute_ignore_ascii_case("identifier")
-                                            .map(|v| v.to_owned())
+                                            .map(Identifier::from)
                                             .unwrap(),
                                     };
                                     let value = child
@@ -570,7 +571,7 @@ impl SubmarineInfo {
                         .map(|v| v.parse::<Point>().unwrap());
                     let tags = child
                         .attribute_ignore_ascii_case("tags")
-                        .map(|v| v.split(',').map(|v| v.to_owned()).collect::<Vec<_>>())
+                        .map(Identifier::split_list)
                         .unwrap_or_default();
                     let job = child
                         .attribute_ignore_ascii_case("job")
@@ -1016,7 +1017,7 @@ pub struct Structure {
     pub map_entity: MapEntity,
 
     pub name: String,
-    pub identifier: String,
+    pub identifier: Identifier,
     pub rect: Rect,
     pub id: u32,
     pub flipped_x: bool,
@@ -1118,7 +1119,7 @@ pub struct ItemSaveableProperties {
     pub invulnerable_to_damage: bool,
     pub allow_stealing: bool,
     pub original_outpost: Option<String>,
-    pub tags: Vec<String>,
+    pub tags: Vec<Identifier>,
     pub display_side_by_side_when_linked: bool,
 }
 
@@ -1183,7 +1184,7 @@ impl ItemSaveableProperties {
                 .map(|v| v.to_owned()),
             tags: element
                 .attribute_ignore_ascii_case("tags")
-                .map(|v| v.split(',').map(|v| v.to_owned()).collect())
+                .map(Identifier::split_list)
                 .unwrap(),
             display_side_by_side_when_linked: element
                 .attribute_ignore_ascii_case("displaysidebysidewhenlinked")
@@ -1196,7 +1197,7 @@ impl ItemSaveableProperties {
 #[derive(Debug)]
 pub struct Item {
     pub name: String,
-    pub identifier: String,
+    pub identifier: Identifier,
     pub pending_swap: Option<String>,
     pub rect: Rect,
     pub id: u32,
@@ -1226,17 +1227,13 @@ impl Item {
         wrap_angle(self.rotation.to_radians())
     }
 
-    pub fn position(&self, prefab: &ItemPrefab) -> Vec2 {
-        if let Some(body) = &prefab.body {
-            todo!();
-            //body.physics_body.position
-        } else {
-            let rect_pos = Vec2 {
-                x: self.rect.x as f32 + self.rect.width as f32 / 2.0,
-                y: self.rect.y as f32 + self.rect.height as f32 / 2.0,
-            };
-
-            rect_pos
+    /// Items with a physics body get it moved to the center of their rect when the submarine is loaded,
+    /// it only drifts away during the simulation which isn't saved, so both cases use the rect center
+    pub fn position(&self) -> Vec2 {
+        //y points up and rects store their top edge, the center is below it like MapEntity.Position in game
+        Vec2 {
+            x: self.rect.x as f32 + self.rect.width as f32 / 2.0,
+            y: self.rect.y as f32 - self.rect.height as f32 / 2.0,
         }
     }
 }
@@ -1269,7 +1266,7 @@ pub struct Hull {
 #[derive(Hash, PartialEq, Eq)]
 pub struct TalentStatIdentifier {
     ty: ItemTalentStat,
-    talent_identifier: String,
+    talent_identifier: Identifier,
 }
 
 #[derive(Hash, PartialEq, Eq)]
@@ -1321,7 +1318,7 @@ pub struct WayPoint {
     pub id_card_description: Option<String>,
     pub id_card_tags: Vec<String>,
     pub exit_point_size: Option<Point>,
-    pub tags: Vec<String>,
+    pub tags: Vec<Identifier>,
     pub job: Option<String>,
     pub ladder_id: Option<u32>,
     pub gap_id: Option<u32>,
@@ -1,5 +1,10 @@
+use std::io::Write;
+
 use md5::{Digest, Md5, digest::Update};
-use roxmltree::Node;
+use quick_xml::events::{BytesText, Event};
+use roxmltree::{Node, NodeType};
+
+use super::identifier::Identifier;
 
 pub trait NodeExp<'a> {
     fn attribute_ignore_ascii_case(&self, name: &str) -> Option<&'a str>;
@@ -25,29 +30,210 @@ pub struct Overridable<T> {
     pub is_override: bool,
 }
 
+/// Computes the UInt key the game uses to sync prefabs over the network
+pub fn prefab_key(identifier: &Identifier) -> u32 {
+    //vanilla barotrauma hashes the lowercase form of the identifier
+    let identifier = identifier.to_lowercase();
+    let hash: [u8; 16] = Md5::new().chain(&identifier).finalize().into();
+
+    ((identifier.len() as u32 & 0xFF) << 24)
+        | ((hash[hash.len() - 3] as u32) << 16)
+        | ((hash[hash.len() - 2] as u32) << 8)
+        | (hash[hash.len() - 1] as u32)
+}
+
+/// Escapes text for XML attribute values and content
+pub fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Node inside a raw element, kept so it can be written back unchanged
+#[derive(Debug, Clone)]
+pub enum RawNode {
+    Element(RawElement),
+    Text(String),
+    Comment(String),
+}
+
+/// Element with its attributes and children in the order they were read
+#[derive(Debug, Clone)]
+pub struct RawElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<RawNode>,
+}
+
+impl RawElement {
+    pub fn new(element: Node) -> Self {
+        Self {
+            name: element.tag_name().name().to_owned(),
+            attributes: element
+                .attributes()
+                .map(|a| (a.name().to_owned(), a.value().to_owned()))
+                .collect(),
+            children: element
+                .children()
+                .filter_map(|child| match child.node_type() {
+                    NodeType::Element => Some(RawNode::Element(Self::new(child))),
+                    NodeType::Text => child.text().map(|v| RawNode::Text(v.to_owned())),
+                    NodeType::Comment => child.text().map(|v| RawNode::Comment(v.to_owned())),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(v, _)| v.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn elements_named<'a>(
+        &'a mut self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (usize, &'a mut RawElement)> + 'a {
+        self.children
+            .iter_mut()
+            .enumerate()
+            .filter_map(move |(i, child)| match child {
+                RawNode::Element(element) if element.name.eq_ignore_ascii_case(name) => {
+                    Some((i, element))
+                }
+                _ => None,
+            })
+    }
+
+    /// Element of a variant the way the game builds it, the elements of `base` with `self` merged into them
+    pub fn create_variant(&self, base: &RawElement) -> RawElement {
+        let mut element = RawElement {
+            name: self.name.clone(),
+            attributes: base.attributes.clone(),
+            children: base
+                .children
+                .iter()
+                .filter(|v| matches!(v, RawNode::Element(_)))
+                .cloned()
+                .collect(),
+        };
+        element.replace(self);
+        element
+    }
+
+    fn replace(&mut self, replacement: &RawElement) {
+        for (name, value) in &replacement.attributes {
+            self.replace_attribute(name, value);
+        }
+        //children are matched by their position among children with the same name, an empty one removes its match
+        let mut removed = Vec::new();
+        let mut seen = Vec::<&str>::new();
+        for child in replacement.children.iter().filter_map(|v| match v {
+            RawNode::Element(element) => Some(element),
+            _ => None,
+        }) {
+            let index = seen
+                .iter()
+                .filter(|v| v.eq_ignore_ascii_case(&child.name))
+                .count();
+            seen.push(&child.name);
+            let matched = self.elements_named(&child.name).nth(index);
+            match matched {
+                Some((i, _))
+                    if child.attributes.is_empty()
+                        && !child
+                            .children
+                            .iter()
+                            .any(|v| matches!(v, RawNode::Element(_))) =>
+                {
+                    removed.push(i)
+                }
+                Some((_, element)) => element.replace(child),
+                None => self.children.push(RawNode::Element(child.clone())),
+            }
+        }
+        removed.sort_unstable();
+        for i in removed.into_iter().rev() {
+            self.children.remove(i);
+        }
+    }
+
+    /// Values starting with `*` or `+` multiply or add to the current value, which stays an integer if both are
+    fn replace_attribute(&mut self, name: &str, value: &str) {
+        let Some((_, existing)) = self
+            .attributes
+            .iter_mut()
+            .find(|(v, _)| v.eq_ignore_ascii_case(name))
+        else {
+            self.attributes.push((name.to_owned(), value.to_owned()));
+            return;
+        };
+        let current = existing.parse::<f32>().unwrap_or_default();
+        let operand = value.get(1..).unwrap_or_default();
+        let result = match value.chars().next() {
+            Some('*') => current * operand.parse::<f32>().unwrap_or_default(),
+            Some('+') => current + operand.parse::<f32>().unwrap_or_default(),
+            _ => {
+                *existing = value.to_owned();
+                return;
+            }
+        };
+        *existing = if existing.contains('.') || operand.contains('.') {
+            result.to_string()
+        } else {
+            (result as i32).to_string()
+        };
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut quick_xml::Writer<W>) -> Result<(), std::io::Error> {
+        let element = writer.create_element(self.name.as_str()).with_attributes(
+            self.attributes
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        );
+        if self.children.is_empty() {
+            element.write_empty()?;
+            return Ok(());
+        }
+        element.write_inner_content(|writer| {
+            for child in &self.children {
+                match child {
+                    RawNode::Element(element) => element.write(writer)?,
+                    RawNode::Text(text) => writer.write_event(Event::Text(BytesText::new(text)))?,
+                    RawNode::Comment(comment) => writer
+                        .write_event(Event::Comment(BytesText::from_escaped(comment.as_str())))?,
+                }
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PrefabWithKey<T> {
     pub key: u32,
+    pub identifier: Identifier,
     pub prefab: T,
 }
 
 impl<T> PrefabWithKey<T> {
-    //identifier must be lowercase to be the same as vanilla barotrauma
-    pub fn new(identifier: &str, prefab: T) -> Self {
+    pub fn new(identifier: &Identifier, prefab: T) -> Self {
         Self {
-            key: {
-                let hash: [u8; 16] = Md5::new().chain(identifier).finalize().into();
-
-                ((identifier.len() as u32 & 0xFF) << 24)
-                    | ((hash[hash.len() - 3] as u32) << 16)
-                    | ((hash[hash.len() - 2] as u32) << 8)
-                    | (hash[hash.len() - 1] as u32)
-            },
+            key: prefab_key(identifier),
+            identifier: identifier.clone(),
             prefab,
         }
     }
 
-    pub fn get_identifier(&self) -> u32 {
+    pub fn get_identifier(&self) -> &Identifier {
+        &self.identifier
+    }
+
+    pub fn get_key(&self) -> u32 {
         self.key
     }
 }
@@ -1,6 +1,7 @@
 use std::{fmt::Display, num::ParseIntError, str::FromStr};
 
-#[derive(Debug, Clone)]
+/// Compared component by component, a missing build or revision is older than any present one
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Version {
     pub major: u32,
     pub minor: u32,
@@ -8,6 +9,13 @@ pub struct Version {
     pub revision: Option<u32>,
 }
 
+impl Version {
+    /// Only major and minor versions are taken into account, patches usually don't break mods
+    pub fn is_older_major_minor(&self, other: &Version) -> bool {
+        (self.major, self.minor) < (other.major, other.minor)
+    }
+}
+
 impl FromStr for Version {
     type Err = ParseVersionError;
 
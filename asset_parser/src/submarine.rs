@@ -0,0 +1,15 @@
+pub mod diff;
+pub mod integrity;
+pub mod power;
+pub mod remap;
+pub mod render;
+pub mod simulation;
+pub mod wiring;
+
+/// Submarine entities identified by their ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EntityKind {
+    Item,
+    Structure,
+    Hull,
+}
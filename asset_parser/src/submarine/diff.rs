@@ -0,0 +1,355 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use roxmltree::{Document, Node};
+
+use crate::{
+    content_file::{SubAssetLoaderError, SubmarineAsset},
+    shared::{prefabs::item_assembly_prefab::Rect, submarine_info::SubmarineInfo},
+};
+
+use super::{
+    EntityKind,
+    wiring::{Pin, WiringEdge, WiringGraph},
+};
+
+/// Attributes covered by other kinds of changes
+const IGNORED_ATTRIBUTES: [&str; 4] = ["id", "rect", "rotation", "linked"];
+
+#[derive(Debug, Clone)]
+pub struct EntityRef {
+    pub kind: EntityKind,
+    pub id: u32,
+    /// Identifier of items and structures, room name of hulls
+    pub name: String,
+}
+
+impl Display for EntityRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} {} ({})", self.kind, self.id, self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Connection {
+    Wire { pin: String, to: Pin },
+    Link(u32),
+}
+
+impl Display for Connection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Connection::Wire { pin, to } => write!(f, "{} -> {}:{}", pin, to.item_id, to.name),
+            Connection::Link(id) => write!(f, "linked to {}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum EntityChange {
+    Added(EntityRef),
+    Removed(EntityRef),
+    Moved {
+        entity: EntityRef,
+        from: Rect,
+        to: Rect,
+    },
+    Rotated {
+        entity: EntityRef,
+        from: f32,
+        to: f32,
+    },
+    Rewired {
+        entity: EntityRef,
+        added: Vec<Connection>,
+        removed: Vec<Connection>,
+    },
+    /// Attribute of the entity or one of its components, named `<component>.<attribute>` for the latter
+    PropertyChanged {
+        entity: EntityRef,
+        property: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+impl Display for EntityChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rect = |v: &Rect| format!("{},{},{},{}", v.x, v.y, v.width, v.height);
+        match self {
+            EntityChange::Added(entity) => write!(f, "+ {}", entity),
+            EntityChange::Removed(entity) => write!(f, "- {}", entity),
+            EntityChange::Moved { entity, from, to } => {
+                write!(f, "~ {} moved from {} to {}", entity, rect(from), rect(to))
+            }
+            EntityChange::Rotated { entity, from, to } => {
+                write!(f, "~ {} rotated from {} to {}", entity, from, to)
+            }
+            EntityChange::Rewired {
+                entity,
+                added,
+                removed,
+            } => {
+                write!(f, "~ {} rewired", entity)?;
+                for connection in removed {
+                    write!(f, "\n    - {}", connection)?;
+                }
+                for connection in added {
+                    write!(f, "\n    + {}", connection)?;
+                }
+                Ok(())
+            }
+            EntityChange::PropertyChanged {
+                entity,
+                property,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ {} {}: {} -> {}",
+                entity,
+                property,
+                from.as_deref().unwrap_or("(none)"),
+                to.as_deref().unwrap_or("(none)")
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SubmarineDiff {
+    pub changes: Vec<EntityChange>,
+}
+
+impl SubmarineDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for SubmarineDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+struct Entity<'a, 'input> {
+    entity: EntityRef,
+    rect: Rect,
+    rotation: f32,
+    element: Option<Node<'a, 'input>>,
+}
+
+fn entities<'a, 'input>(
+    info: &SubmarineInfo,
+    root: Node<'a, 'input>,
+) -> BTreeMap<(EntityKind, u32), Entity<'a, 'input>> {
+    let mut elements = BTreeMap::new();
+    for element in root.children().filter(|v| v.is_element()) {
+        let kind = match element.tag_name().name() {
+            "Item" => EntityKind::Item,
+            "Structure" => EntityKind::Structure,
+            "Hull" => EntityKind::Hull,
+            _ => continue,
+        };
+        if let Some(id) = attribute(element, "id").and_then(|v| v.parse().ok()) {
+            elements.insert((kind, id), element);
+        }
+    }
+
+    let mut entities = BTreeMap::new();
+    let mut insert = |kind, id, name: String, rect, rotation| {
+        entities.insert(
+            (kind, id),
+            Entity {
+                entity: EntityRef { kind, id, name },
+                rect,
+                rotation,
+                element: elements.get(&(kind, id)).copied(),
+            },
+        );
+    };
+    for item in &info.items {
+        insert(
+            EntityKind::Item,
+            item.id,
+            item.identifier.to_string(),
+            item.rect,
+            item.rotation,
+        );
+    }
+    for structure in &info.structures {
+        insert(
+            EntityKind::Structure,
+            structure.id,
+            structure.identifier.to_string(),
+            structure.rect,
+            structure.rotation,
+        );
+    }
+    for hull in &info.hulls {
+        insert(
+            EntityKind::Hull,
+            hull.id,
+            hull.room_name.clone(),
+            hull.rect,
+            0.0,
+        );
+    }
+    entities
+}
+
+fn attribute<'a>(element: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    element
+        .attributes()
+        .find(|v| v.name().eq_ignore_ascii_case(name))
+        .map(|v| v.value())
+}
+
+/// Attributes of an entity and its components, wiring is compared separately
+fn properties(element: Node) -> BTreeMap<String, String> {
+    let mut properties = BTreeMap::new();
+    for attribute in element.attributes() {
+        if !IGNORED_ATTRIBUTES
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(attribute.name()))
+        {
+            properties.insert(
+                attribute.name().to_lowercase(),
+                attribute.value().to_owned(),
+            );
+        }
+    }
+    let mut component_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for child in element.children().filter(|v| v.is_element()) {
+        let tag_name = child.tag_name().name();
+        if tag_name.eq_ignore_ascii_case("ConnectionPanel") {
+            continue;
+        }
+        let count = component_counts.entry(tag_name).or_default();
+        let component = match *count {
+            0 => tag_name.to_owned(),
+            i => format!("{}[{}]", tag_name, i),
+        };
+        *count += 1;
+        for attribute in child.attributes() {
+            properties.insert(
+                format!("{}.{}", component, attribute.name().to_lowercase()),
+                attribute.value().to_owned(),
+            );
+        }
+    }
+    properties
+}
+
+fn connections(info: &SubmarineInfo) -> BTreeMap<u32, BTreeSet<Connection>> {
+    let mut connections: BTreeMap<u32, BTreeSet<Connection>> = BTreeMap::new();
+    for edge in WiringGraph::new(info).edges {
+        match edge {
+            WiringEdge::Wire { from, to, .. } => {
+                connections
+                    .entry(from.item_id)
+                    .or_default()
+                    .insert(Connection::Wire {
+                        pin: from.name.clone(),
+                        to: to.clone(),
+                    });
+                connections
+                    .entry(to.item_id)
+                    .or_default()
+                    .insert(Connection::Wire {
+                        pin: to.name,
+                        to: from,
+                    });
+            }
+            WiringEdge::Link { from, to } => {
+                connections
+                    .entry(from)
+                    .or_default()
+                    .insert(Connection::Link(to));
+            }
+        }
+    }
+    connections
+}
+
+/// Compares two gzipped submarine files, matching items, structures and hulls by their ID
+pub fn diff_submarines(old: &[u8], new: &[u8]) -> Result<SubmarineDiff, SubAssetLoaderError> {
+    let (old_raw, new_raw) = (
+        SubmarineAsset::decompress(old)?,
+        SubmarineAsset::decompress(new)?,
+    );
+    let (old_document, new_document) = (Document::parse(&old_raw)?, Document::parse(&new_raw)?);
+    let old_info = SubmarineInfo::new(old_document.root_element());
+    let new_info = SubmarineInfo::new(new_document.root_element());
+
+    let old_entities = entities(&old_info, old_document.root_element());
+    let mut new_entities = entities(&new_info, new_document.root_element());
+    let old_connections = connections(&old_info);
+    let new_connections = connections(&new_info);
+
+    let mut diff = SubmarineDiff::default();
+    for (key, old) in old_entities {
+        let Some(new) = new_entities.remove(&key) else {
+            diff.changes.push(EntityChange::Removed(old.entity));
+            continue;
+        };
+        let entity = new.entity;
+        if old.rect != new.rect {
+            diff.changes.push(EntityChange::Moved {
+                entity: entity.clone(),
+                from: old.rect,
+                to: new.rect,
+            });
+        }
+        if old.rotation != new.rotation {
+            diff.changes.push(EntityChange::Rotated {
+                entity: entity.clone(),
+                from: old.rotation,
+                to: new.rotation,
+            });
+        }
+        if entity.kind == EntityKind::Item {
+            let empty = BTreeSet::new();
+            let old = old_connections.get(&entity.id).unwrap_or(&empty);
+            let new = new_connections.get(&entity.id).unwrap_or(&empty);
+            if old != new {
+                diff.changes.push(EntityChange::Rewired {
+                    entity: entity.clone(),
+                    added: new.difference(old).cloned().collect(),
+                    removed: old.difference(new).cloned().collect(),
+                });
+            }
+        }
+        let mut old_properties = old.element.map(properties).unwrap_or_default();
+        let new_properties = new.element.map(properties).unwrap_or_default();
+        for (property, to) in new_properties {
+            let from = old_properties.remove(&property);
+            if from.as_ref() != Some(&to) {
+                diff.changes.push(EntityChange::PropertyChanged {
+                    entity: entity.clone(),
+                    property,
+                    from,
+                    to: Some(to),
+                });
+            }
+        }
+        for (property, from) in old_properties {
+            diff.changes.push(EntityChange::PropertyChanged {
+                entity: entity.clone(),
+                property,
+                from: Some(from),
+                to: None,
+            });
+        }
+    }
+    for (_, new) in new_entities {
+        diff.changes.push(EntityChange::Added(new.entity));
+    }
+    Ok(diff)
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+use glam::Vec2;
+
+use crate::shared::{
+    identifier::Identifier,
+    prefabs::item_assembly_prefab::Rect,
+    submarine_info::{SubmarineInfo, WayPoint},
+};
+
+/// How far past the edges of a gap to look for the hulls it connects
+const GAP_SEARCH_DISTANCE: i32 = 16;
+
+#[derive(Debug, Clone)]
+pub enum IntegrityIssue {
+    /// Water can't flow through a gap that doesn't touch any hull
+    UnconnectedGap {
+        gap_id: u32,
+    },
+    /// Gap with the same hull on both sides
+    GapWithinSingleHull {
+        gap_id: u32,
+        hull_id: u32,
+    },
+    ItemOutsideHull {
+        item_id: u32,
+        identifier: Identifier,
+    },
+    WaypointOutsideHull {
+        waypoint_id: u32,
+    },
+    /// Path waypoints bots can't reach from the rest of the waypoint graph
+    DisconnectedWaypoints {
+        waypoint_ids: Vec<u32>,
+    },
+    /// Hull without path waypoints, bots can't walk through it
+    HullWithoutWaypoints {
+        hull_id: u32,
+        room_name: String,
+    },
+}
+
+impl Display for IntegrityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntegrityIssue::UnconnectedGap { gap_id } => {
+                write!(f, "Gap {} doesn't connect to any hull", gap_id)
+            }
+            IntegrityIssue::GapWithinSingleHull { gap_id, hull_id } => {
+                write!(f, "Gap {} has hull {} on both sides", gap_id, hull_id)
+            }
+            IntegrityIssue::ItemOutsideHull {
+                item_id,
+                identifier,
+            } => write!(
+                f,
+                "Item {} ({}) is outside of any hull",
+                item_id, identifier
+            ),
+            IntegrityIssue::WaypointOutsideHull { waypoint_id } => {
+                write!(f, "Waypoint {} is outside of any hull", waypoint_id)
+            }
+            IntegrityIssue::DisconnectedWaypoints { waypoint_ids } => write!(
+                f,
+                "Waypoints {:?} are disconnected from the rest of the waypoint graph",
+                waypoint_ids
+            ),
+            IntegrityIssue::HullWithoutWaypoints { hull_id, room_name } => {
+                write!(f, "Hull {} ({}) has no path waypoints", hull_id, room_name)
+            }
+        }
+    }
+}
+
+/// Submarine rects have their origin in the top left corner with the y axis pointing up
+fn rect_contains(rect: &Rect, point: Vec2) -> bool {
+    point.x >= rect.x as f32
+        && point.x <= (rect.x + rect.width as i32) as f32
+        && point.y <= rect.y as f32
+        && point.y >= (rect.y - rect.height as i32) as f32
+}
+
+fn rects_overlap(a: &Rect, b: &Rect) -> bool {
+    a.x < b.x + b.width as i32
+        && b.x < a.x + a.width as i32
+        && a.y - (a.height as i32) < b.y
+        && b.y - (b.height as i32) < a.y
+}
+
+/// Hulls on either side of a gap, left/right for horizontal gaps and top/bottom for vertical ones
+fn gap_sides(info: &SubmarineInfo, rect: &Rect, horizontal: bool) -> [Option<u32>; 2] {
+    let search_rects = if horizontal {
+        [
+            Rect {
+                x: rect.x - GAP_SEARCH_DISTANCE,
+                width: GAP_SEARCH_DISTANCE as u32,
+                ..*rect
+            },
+            Rect {
+                x: rect.x + rect.width as i32,
+                width: GAP_SEARCH_DISTANCE as u32,
+                ..*rect
+            },
+        ]
+    } else {
+        [
+            Rect {
+                y: rect.y + GAP_SEARCH_DISTANCE,
+                height: GAP_SEARCH_DISTANCE as u32,
+                ..*rect
+            },
+            Rect {
+                y: rect.y - rect.height as i32,
+                height: GAP_SEARCH_DISTANCE as u32,
+                ..*rect
+            },
+        ]
+    };
+    search_rects.map(|search_rect| {
+        info.hulls
+            .iter()
+            .find(|hull| rects_overlap(&hull.rect, &search_rect))
+            .map(|hull| hull.id)
+    })
+}
+
+fn find_hull(info: &SubmarineInfo, point: Vec2) -> Option<u32> {
+    info.hulls
+        .iter()
+        .find(|hull| rect_contains(&hull.rect, point))
+        .map(|hull| hull.id)
+}
+
+pub fn check_integrity(info: &SubmarineInfo) -> Vec<IntegrityIssue> {
+    let mut issues = Vec::new();
+
+    for gap in &info.gaps {
+        match gap_sides(info, &gap.rect, gap.horizontal) {
+            [None, None] => issues.push(IntegrityIssue::UnconnectedGap { gap_id: gap.id }),
+            [Some(a), Some(b)] if a == b => issues.push(IntegrityIssue::GapWithinSingleHull {
+                gap_id: gap.id,
+                hull_id: a,
+            }),
+            _ => {}
+        }
+    }
+
+    //wires are drawn between items, doors and docking ports sit in walls between hulls or the outside
+    for item in info.items.iter().filter(|v| {
+        v.components.wire.is_none()
+            && v.components.door.is_none()
+            && v.components.docking_port.is_none()
+    }) {
+        //items mounted on the outer walls like engines and turrets only reach into a hull
+        let touches_hull = info
+            .hulls
+            .iter()
+            .any(|v| rects_overlap(&v.rect, &item.rect))
+            || info.gaps.iter().any(|v| rects_overlap(&v.rect, &item.rect));
+        if !touches_hull && find_hull(info, item.position()).is_none() {
+            issues.push(IntegrityIssue::ItemOutsideHull {
+                item_id: item.id,
+                identifier: item.identifier.clone(),
+            });
+        }
+    }
+
+    let mut hulls_with_waypoints = Vec::new();
+    for waypoint in &info.waypoints {
+        match find_hull(info, Vec2::new(waypoint.x as f32, waypoint.y as f32)) {
+            //spawn points aren't part of the graph bots walk along
+            Some(hull_id) if waypoint.spawn_type.path() => hulls_with_waypoints.push(hull_id),
+            Some(_) => {}
+            None => issues.push(IntegrityIssue::WaypointOutsideHull {
+                waypoint_id: waypoint.id,
+            }),
+        }
+    }
+    for hull in &info.hulls {
+        if !hulls_with_waypoints.contains(&hull.id) {
+            issues.push(IntegrityIssue::HullWithoutWaypoints {
+                hull_id: hull.id,
+                room_name: hull.room_name.clone(),
+            });
+        }
+    }
+
+    let path_waypoints = info
+        .waypoints
+        .iter()
+        .filter(|v| v.spawn_type.path())
+        .collect::<Vec<_>>();
+    let mut components = waypoint_components(&path_waypoints);
+    //the biggest component is the main graph, everything else can't be reached from it
+    components.sort_by_key(|v| std::cmp::Reverse(v.len()));
+    for waypoint_ids in components.into_iter().skip(1) {
+        issues.push(IntegrityIssue::DisconnectedWaypoints { waypoint_ids });
+    }
+
+    issues
+}
+
+fn waypoint_components(waypoints: &[&WayPoint]) -> Vec<Vec<u32>> {
+    let indices = waypoints
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.id, i))
+        .collect::<HashMap<_, _>>();
+    let mut parent = (0..waypoints.len()).collect::<Vec<_>>();
+    fn find(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    for (i, waypoint) in waypoints.iter().enumerate() {
+        for &j in waypoint
+            .linked_to_ids
+            .iter()
+            .filter_map(|id| indices.get(id))
+        {
+            let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+            parent[a] = b;
+        }
+    }
+    let mut components: BTreeMap<usize, Vec<u32>> = BTreeMap::new();
+    for (i, waypoint) in waypoints.iter().enumerate() {
+        let root = find(&mut parent, i);
+        components.entry(root).or_default().push(waypoint.id);
+    }
+    components.into_values().collect()
+}
@@ -0,0 +1,289 @@
+use std::{collections::HashMap, fmt::Write};
+
+use crate::shared::{
+    identifier::Identifier, item_components::ItemComponents, submarine_info::SubmarineInfo,
+};
+
+use super::wiring::{Pin, WiringEdge, WiringGraph};
+
+#[derive(Debug, Clone)]
+pub struct PowerItem {
+    pub item_id: u32,
+    pub identifier: Identifier,
+    pub power: f32,
+}
+
+/// Items connected through power wiring, junction boxes and relays that are turned on
+#[derive(Debug, Default)]
+pub struct PowerGrid {
+    /// Reactors and batteries supplying the grid with their max output
+    pub suppliers: Vec<PowerItem>,
+    /// Consumers with their declared consumption, charging batteries use their max recharge speed
+    pub consumers: Vec<PowerItem>,
+    pub junction_boxes: Vec<u32>,
+}
+
+impl PowerGrid {
+    pub fn supply(&self) -> f32 {
+        self.suppliers.iter().map(|v| v.power).sum()
+    }
+
+    pub fn consumption(&self) -> f32 {
+        self.consumers.iter().map(|v| v.power).sum()
+    }
+
+    /// Power flowing through the grid, reactors and batteries throttle their output down to the consumption
+    pub fn load(&self) -> f32 {
+        self.consumption().min(self.supply())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PowerReport {
+    pub grids: Vec<PowerGrid>,
+    /// Consumers on a grid without any supplier, including unwired ones
+    pub unpowered_consumers: Vec<PowerItem>,
+    /// Relays whose max power the load of their grid exceeds, and junction boxes that overload in the worst case
+    /// of every supplier running at its max output, like a reactor driven manually at full power.
+    /// The power is the load or the max output that overloads them
+    pub overloaded: Vec<PowerItem>,
+}
+
+fn is_power_pin(name: &str) -> bool {
+    name.starts_with("power")
+}
+
+/// Declared consumption of every powered component of an item
+fn power_consumption(c: &ItemComponents) -> Option<f32> {
+    let consumptions = [
+        c.powered.as_ref().map(|v| v.power_consumption),
+        c.engine.as_ref().map(|v| v.powered.power_consumption),
+        c.pump.as_ref().map(|v| v.powered.power_consumption),
+        c.fabricator.as_ref().map(|v| v.powered.power_consumption),
+        c.deconstructor
+            .as_ref()
+            .map(|v| v.powered.power_consumption),
+        c.minimap.as_ref().map(|v| v.powered.power_consumption),
+        c.oxygen_generator
+            .as_ref()
+            .map(|v| v.powered.power_consumption),
+        c.sonar.as_ref().map(|v| v.powered.power_consumption),
+        c.sonar_transducer
+            .as_ref()
+            .map(|v| v.powered.power_consumption),
+        c.steering.as_ref().map(|v| v.powered.power_consumption),
+        c.turret.as_ref().map(|v| v.powered.power_consumption),
+        c.electrical_discharger
+            .as_ref()
+            .map(|v| v.powered.power_consumption),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(c.light.iter().map(|v| v.powered.power_consumption))
+    .collect::<Vec<_>>();
+    (!consumptions.is_empty()).then(|| consumptions.iter().sum())
+}
+
+struct UnionFind(Vec<usize>);
+
+impl UnionFind {
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.0[i] != i {
+            self.0[i] = self.0[self.0[i]];
+            i = self.0[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.0[a] = b;
+    }
+}
+
+pub fn analyze_power(info: &SubmarineInfo) -> PowerReport {
+    let mut pins = Vec::new();
+    let mut pin_index = HashMap::new();
+    for item in &info.items {
+        let Some(panel) = &item.components.connection_panel else {
+            continue;
+        };
+        for connection in panel.connections.iter().filter(|v| is_power_pin(&v.name)) {
+            let pin = Pin {
+                item_id: item.id,
+                name: connection.name.clone(),
+            };
+            pin_index.insert(pin.clone(), pins.len());
+            pins.push(pin);
+        }
+    }
+
+    let mut union_find = UnionFind((0..pins.len()).collect());
+    for edge in WiringGraph::new(info).edges {
+        if let WiringEdge::Wire { from, to, .. } = edge
+            && let (Some(a), Some(b)) = (pin_index.get(&from), pin_index.get(&to))
+        {
+            union_find.union(*a, *b);
+        }
+    }
+    for item in &info.items {
+        let passes_power = match (&item.components.relay, &item.components.power_transfer) {
+            (Some(relay), _) => relay.is_on,
+            (None, Some(_)) => true,
+            (None, None) => false,
+        };
+        if !passes_power {
+            continue;
+        }
+        let item_pins = pins
+            .iter()
+            .enumerate()
+            .filter(|(_, pin)| pin.item_id == item.id)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        for pair in item_pins.windows(2) {
+            union_find.union(pair[0], pair[1]);
+        }
+    }
+
+    let mut grids: HashMap<usize, PowerGrid> = HashMap::new();
+    let mut grid_of = |item_id: u32, names: &[&str]| {
+        names.iter().find_map(|name| {
+            pin_index
+                .get(&Pin {
+                    item_id,
+                    name: (*name).to_owned(),
+                })
+                .map(|i| union_find.find(*i))
+        })
+    };
+    for item in &info.items {
+        let c = &item.components;
+        let power_item = |power| PowerItem {
+            item_id: item.id,
+            identifier: item.identifier.clone(),
+            power,
+        };
+        if let Some(reactor) = &c.reactor {
+            if let Some(grid) = grid_of(item.id, &["power_out"]) {
+                grids
+                    .entry(grid)
+                    .or_default()
+                    .suppliers
+                    .push(power_item(reactor.max_power_output));
+            }
+        } else if let Some(battery) = &c.power_container {
+            if let Some(grid) = grid_of(item.id, &["power_out"]) {
+                grids
+                    .entry(grid)
+                    .or_default()
+                    .suppliers
+                    .push(power_item(battery.max_output));
+            }
+            if let Some(grid) = grid_of(item.id, &["power_in"]) {
+                grids
+                    .entry(grid)
+                    .or_default()
+                    .consumers
+                    .push(power_item(battery.max_recharge_speed));
+            }
+        } else if c.relay.is_some() || c.power_transfer.is_some() {
+            if let Some(grid) = grid_of(item.id, &["power", "power_in", "power_out"]) {
+                grids.entry(grid).or_default().junction_boxes.push(item.id);
+            }
+        } else if let Some(consumption) = power_consumption(c) {
+            //items without power pins run on batteries inside them
+            if let Some(grid) = grid_of(item.id, &["power_in", "power"]) {
+                grids
+                    .entry(grid)
+                    .or_default()
+                    .consumers
+                    .push(power_item(consumption));
+            }
+        }
+    }
+
+    let mut report = PowerReport::default();
+    for grid in grids.into_values() {
+        if grid.suppliers.is_empty() {
+            report
+                .unpowered_consumers
+                .extend(grid.consumers.iter().filter(|v| v.power > 0.0).cloned());
+        }
+        let (load, supply, consumption) = (grid.load(), grid.supply(), grid.consumption());
+        for item in info
+            .items
+            .iter()
+            .filter(|v| grid.junction_boxes.contains(&v.id))
+        {
+            let c = &item.components;
+            let transfer = c
+                .relay
+                .as_ref()
+                .map(|v| &v.power_transfer)
+                .or(c.power_transfer.as_ref());
+            //the voltage of a grid is the ratio of the power output to the consumption, it only goes past 1 when
+            //a reactor is driven manually, so the max output of every supplier is checked
+            let power = if c.relay.as_ref().is_some_and(|v| load > v.max_power) {
+                Some(load)
+            } else if transfer.is_some_and(|v| {
+                v.can_be_overloaded
+                    && consumption > 0.0
+                    && supply / consumption > v.overload_voltage
+            }) {
+                Some(supply)
+            } else {
+                None
+            };
+            if let Some(power) = power {
+                report.overloaded.push(PowerItem {
+                    item_id: item.id,
+                    identifier: item.identifier.clone(),
+                    power,
+                });
+            }
+        }
+        report.grids.push(grid);
+    }
+    report
+        .grids
+        .sort_by(|a, b| b.supply().total_cmp(&a.supply()));
+    report
+}
+
+impl PowerReport {
+    /// Plain text table with a row per grid followed by the problematic items
+    pub fn to_table(&self) -> String {
+        let mut s = format!(
+            "{:<6} {:>10} {:>12} {:>9} {:>9} {:>14}\n",
+            "Grid", "Supply", "Consumption", "Suppliers", "Consumers", "Junction boxes"
+        );
+        for (i, grid) in self.grids.iter().enumerate() {
+            let _ = writeln!(
+                s,
+                "{:<6} {:>10.1} {:>12.1} {:>9} {:>9} {:>14}",
+                i,
+                grid.supply(),
+                grid.consumption(),
+                grid.suppliers.len(),
+                grid.consumers.len(),
+                grid.junction_boxes.len()
+            );
+        }
+        for item in &self.unpowered_consumers {
+            let _ = writeln!(
+                s,
+                "Unpowered: {} #{} ({:.1} kW)",
+                item.identifier, item.item_id, item.power
+            );
+        }
+        for item in &self.overloaded {
+            let _ = writeln!(
+                s,
+                "Overloaded: {} #{} ({:.1} kW)",
+                item.identifier, item.item_id, item.power
+            );
+        }
+        s
+    }
+}
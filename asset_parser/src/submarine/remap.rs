@@ -0,0 +1,116 @@
+use std::{collections::HashMap, fmt::Display, io::Write, ops::Range};
+
+use flate2::{Compression, write::GzEncoder};
+use roxmltree::Document;
+
+use crate::{
+    content_file::{SubAssetLoaderError, SubmarineAsset},
+    shared::{identifier::Identifier, util::escape_xml},
+};
+
+use super::EntityKind;
+
+#[derive(Debug, Clone)]
+pub struct RemappedEntity {
+    pub kind: EntityKind,
+    pub id: u32,
+    pub from: Identifier,
+    pub to: Identifier,
+}
+
+impl Display for RemappedEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} {}: {} -> {}",
+            self.kind, self.id, self.from, self.to
+        )
+    }
+}
+
+/// Identifier replacements planned for a submarine file, the rest of the file is kept as is
+#[derive(Debug)]
+pub struct SubmarineRemap {
+    raw: String,
+    file_path: String,
+    /// Entities affected by the mapping, also used as the dry run report
+    pub entities: Vec<RemappedEntity>,
+    replacements: Vec<(Range<usize>, String)>,
+}
+
+impl SubmarineRemap {
+    pub fn load_from_path(
+        file_path: String,
+        mapping: &HashMap<Identifier, Identifier>,
+    ) -> Result<Self, SubAssetLoaderError> {
+        let bytes = std::fs::read(&file_path)?;
+        Self::new(&bytes, file_path, mapping)
+    }
+
+    pub fn new(
+        bytes: &[u8],
+        file_path: String,
+        mapping: &HashMap<Identifier, Identifier>,
+    ) -> Result<Self, SubAssetLoaderError> {
+        let raw = SubmarineAsset::decompress(bytes)?;
+        let mut entities = Vec::new();
+        let mut replacements = Vec::new();
+        {
+            let document = Document::parse(&raw)?;
+            //linked submarines embed their entities, so every descendant is checked
+            for element in document.descendants().filter(|v| v.is_element()) {
+                let kind = match element.tag_name().name() {
+                    "Item" => EntityKind::Item,
+                    "Structure" => EntityKind::Structure,
+                    _ => continue,
+                };
+                let Some(attribute) = element
+                    .attributes()
+                    .find(|v| v.name().eq_ignore_ascii_case("identifier"))
+                else {
+                    continue;
+                };
+                let from = Identifier::from(attribute.value());
+                let Some(to) = mapping.get(&from) else {
+                    continue;
+                };
+                replacements.push((attribute.range_value(), escape_xml(to.as_str())));
+                entities.push(RemappedEntity {
+                    kind,
+                    id: element
+                        .attributes()
+                        .find(|v| v.name().eq_ignore_ascii_case("id"))
+                        .and_then(|v| v.value().parse().ok())
+                        .unwrap_or_default(),
+                    from,
+                    to: to.clone(),
+                });
+            }
+        }
+        Ok(Self {
+            raw,
+            file_path,
+            entities,
+            replacements,
+        })
+    }
+
+    /// Rewrites the identifiers and gzips the result, which is loaded again to make sure it's still a valid submarine
+    pub fn apply(&self) -> Result<(SubmarineAsset, Vec<u8>), SubAssetLoaderError> {
+        let mut raw = String::with_capacity(self.raw.len());
+        let mut last = 0;
+        for (range, value) in &self.replacements {
+            raw.push_str(&self.raw[last..range.start]);
+            raw.push_str(value);
+            last = range.end;
+        }
+        raw.push_str(&self.raw[last..]);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(raw.as_bytes())?;
+        let bytes = encoder.finish()?;
+
+        let asset = SubmarineAsset::load(&bytes, self.file_path.clone())?;
+        Ok((asset, bytes))
+    }
+}
@@ -0,0 +1,230 @@
+use std::{collections::HashMap, fmt::Write, sync::Arc};
+
+use glam::Vec2;
+
+use crate::{
+    content_package::{AnyContentPackage, ContentFiles},
+    shared::{
+        identifier::Identifier,
+        math::quad2d::Quad2D,
+        prefabs::{item_prefab::ItemPrefab, structure_prefab::StructurePrefab},
+        submarine_info::SubmarineInfo,
+        util::escape_xml,
+    },
+};
+
+const MARGIN: f32 = 64.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct RenderLayers {
+    pub hulls: bool,
+    pub gaps: bool,
+    pub structures: bool,
+    pub items: bool,
+    pub waypoints: bool,
+    pub linked_submarines: bool,
+}
+
+impl Default for RenderLayers {
+    fn default() -> Self {
+        Self {
+            hulls: true,
+            gaps: true,
+            structures: true,
+            items: true,
+            waypoints: true,
+            linked_submarines: true,
+        }
+    }
+}
+
+/// Renders submarines to SVG, entities are drawn as their transformed quads in game coordinates with the y axis flipped
+#[derive(Debug, Default)]
+pub struct SvgRenderer<'a> {
+    pub layers: RenderLayers,
+    item_prefabs: Option<HashMap<&'a Identifier, &'a ItemPrefab>>,
+    structure_prefabs: Option<HashMap<&'a Identifier, &'a StructurePrefab>>,
+}
+
+impl<'a> SvgRenderer<'a> {
+    pub fn new(layers: RenderLayers) -> Self {
+        Self {
+            layers,
+            ..Default::default()
+        }
+    }
+
+    /// Uses the prefabs of the loaded content to scale structures, entities with undefined prefabs are drawn in red
+    pub fn with_content(mut self, packages: &'a [(Arc<AnyContentPackage>, ContentFiles)]) -> Self {
+        let mut item_prefabs = HashMap::new();
+        let mut structure_prefabs = HashMap::new();
+        for (_, files) in packages {
+            for item in files.items.iter().flat_map(|file| &file.items) {
+                item_prefabs.insert(item.value.get_identifier(), &item.value);
+            }
+            for structure in files.structures.iter().flat_map(|file| &file.prefabs) {
+                structure_prefabs.insert(structure.value.get_identifier(), &structure.value);
+            }
+        }
+        self.item_prefabs = Some(item_prefabs);
+        self.structure_prefabs = Some(structure_prefabs);
+        self
+    }
+
+    pub fn render(&self, info: &SubmarineInfo) -> String {
+        let mut body = String::new();
+        let mut bounds = Bounds::default();
+
+        if self.layers.hulls {
+            body.push_str(
+                "<g id=\"hulls\" fill=\"#3a6ea5\" fill-opacity=\"0.25\" stroke=\"#3a6ea5\">\n",
+            );
+            for hull in &info.hulls {
+                let quad = Quad2D::from_submarine_rectangle(hull.rect);
+                bounds.add_quad(&quad);
+                polygon(&mut body, &quad, None);
+            }
+            body.push_str("</g>\n");
+        }
+        if self.layers.structures {
+            body.push_str("<g id=\"structures\" fill=\"#8c8c8c\" stroke=\"#505050\">\n");
+            for structure in &info.structures {
+                let prefab = self
+                    .structure_prefabs
+                    .as_ref()
+                    .map(|v| v.get(&structure.identifier));
+                let quad = match prefab {
+                    Some(Some(prefab)) => structure.get_transformed_quad(prefab),
+                    _ => Quad2D::from_submarine_rectangle(structure.rect),
+                };
+                bounds.add_quad(&quad);
+                polygon(
+                    &mut body,
+                    &quad,
+                    matches!(prefab, Some(None)).then_some("red"),
+                );
+            }
+            body.push_str("</g>\n");
+        }
+        if self.layers.gaps {
+            body.push_str("<g id=\"gaps\" fill=\"#e08e2b\" fill-opacity=\"0.6\">\n");
+            for gap in &info.gaps {
+                let quad = Quad2D::from_submarine_rectangle(gap.rect);
+                bounds.add_quad(&quad);
+                polygon(&mut body, &quad, None);
+            }
+            body.push_str("</g>\n");
+        }
+        if self.layers.items {
+            body.push_str(
+                "<g id=\"items\" fill=\"#4caf50\" fill-opacity=\"0.5\" stroke=\"#2e7d32\">\n",
+            );
+            for item in &info.items {
+                let prefab = self.item_prefabs.as_ref().map(|v| v.get(&item.identifier));
+                let quad = match prefab {
+                    Some(Some(prefab)) => item.get_transformed_quad(prefab),
+                    _ => Quad2D::from_submarine_rectangle(item.rect).rotated(-item.rotation_rad()),
+                };
+                bounds.add_quad(&quad);
+                polygon(
+                    &mut body,
+                    &quad,
+                    matches!(prefab, Some(None)).then_some("red"),
+                );
+            }
+            body.push_str("</g>\n");
+        }
+        if self.layers.waypoints {
+            body.push_str("<g id=\"waypoints\" stroke=\"#c2185b\" fill=\"#c2185b\">\n");
+            let positions = info
+                .waypoints
+                .iter()
+                .map(|v| (v.id, Vec2::new(v.x as f32, v.y as f32)))
+                .collect::<HashMap<_, _>>();
+            for waypoint in &info.waypoints {
+                let from = positions[&waypoint.id];
+                for to in waypoint
+                    .linked_to_ids
+                    .iter()
+                    .filter(|id| waypoint.id < **id)
+                    .filter_map(|id| positions.get(id))
+                {
+                    let _ = writeln!(
+                        body,
+                        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>",
+                        from.x, -from.y, to.x, -to.y
+                    );
+                }
+            }
+            for position in positions.values() {
+                bounds.add(*position);
+                let _ = writeln!(
+                    body,
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"8\"/>",
+                    position.x, -position.y
+                );
+            }
+            body.push_str("</g>\n");
+        }
+        if self.layers.linked_submarines {
+            body.push_str("<g id=\"linked_submarines\" fill=\"#7b1fa2\">\n");
+            for linked in &info.linked_submarines {
+                bounds.add(linked.pos);
+                let _ = writeln!(
+                    body,
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"24\"/>\n<text x=\"{}\" y=\"{}\" font-size=\"48\">{}</text>",
+                    linked.pos.x,
+                    -linked.pos.y,
+                    linked.pos.x + 32.0,
+                    -linked.pos.y,
+                    escape_xml(&linked.file_path)
+                );
+            }
+            body.push_str("</g>\n");
+        }
+
+        let (min, max) = bounds.min.zip(bounds.max).unwrap_or_default();
+        let (width, height) = (max.x - min.x + MARGIN * 2.0, max.y - min.y + MARGIN * 2.0);
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">\n{}</svg>\n",
+            min.x - MARGIN,
+            -max.y - MARGIN,
+            width,
+            height,
+            width,
+            height,
+            body
+        )
+    }
+}
+
+#[derive(Default)]
+struct Bounds {
+    min: Option<Vec2>,
+    max: Option<Vec2>,
+}
+
+impl Bounds {
+    fn add(&mut self, point: Vec2) {
+        self.min = Some(self.min.map_or(point, |v| v.min(point)));
+        self.max = Some(self.max.map_or(point, |v| v.max(point)));
+    }
+
+    fn add_quad(&mut self, quad: &Quad2D) {
+        for point in [quad.a, quad.b, quad.c, quad.d] {
+            self.add(point);
+        }
+    }
+}
+
+fn polygon(s: &mut String, quad: &Quad2D, fill: Option<&str>) {
+    let points = [quad.a, quad.b, quad.c, quad.d]
+        .iter()
+        .map(|v| format!("{},{}", v.x, -v.y))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let _ = match fill {
+        Some(fill) => writeln!(s, "<polygon points=\"{}\" fill=\"{}\"/>", points, fill),
+        None => writeln!(s, "<polygon points=\"{}\"/>", points),
+    };
+}
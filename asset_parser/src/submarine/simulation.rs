@@ -0,0 +1,998 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use regex::Regex;
+
+use crate::shared::{
+    item_components::{
+        ItemComponents, arithmetic_component::ArithmeticComponent,
+        boolean_operator::BooleanOperatorComponent, equals_component::EqualsComponent,
+        function_component, oscillator::WaveType, trigonometric_function_component,
+    },
+    submarine_info::SubmarineInfo,
+};
+
+use super::wiring::{Pin, WiringEdge, WiringGraph};
+
+/// The game updates items 60 times per second
+pub const DEFAULT_DELTA_TIME: f32 = 1.0 / 60.0;
+
+/// Signals stop propagating after passing through this many components in a single tick, guards against feedback loops
+const MAX_SIGNAL_STEPS: u32 = 32;
+
+#[derive(Debug, Clone, Copy)]
+enum BooleanKind {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CompareKind {
+    Equals,
+    Greater,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ArithmeticKind {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum UnaryKind {
+    Function(function_component::FunctionType),
+    Trigonometric(trigonometric_function_component::FunctionType, bool),
+    Modulo(f32),
+    Exponentiation(f32),
+}
+
+/// The two `signal_in` pins of a component, a signal stays valid for `time_frame` seconds after being received
+#[derive(Debug)]
+struct TimedInputs {
+    time_frame: f32,
+    values: [Option<String>; 2],
+    since_received: [f32; 2],
+}
+
+impl TimedInputs {
+    fn new(time_frame: f32) -> Self {
+        Self {
+            time_frame,
+            values: [None, None],
+            since_received: [f32::INFINITY; 2],
+        }
+    }
+
+    fn receive(&mut self, pin: &str, value: &str) {
+        let i = match pin {
+            "signal_in1" => 0,
+            "signal_in2" => 1,
+            _ => return,
+        };
+        self.values[i] = Some(value.to_owned());
+        self.since_received[i] = 0.0;
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.since_received[i] <= self.time_frame
+    }
+
+    fn valid_values(&self) -> Option<(&str, &str)> {
+        if self.is_valid(0) && self.is_valid(1) {
+            self.values[0].as_deref().zip(self.values[1].as_deref())
+        } else {
+            None
+        }
+    }
+
+    fn advance(&mut self, delta_time: f32) {
+        for v in &mut self.since_received {
+            *v += delta_time;
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Logic {
+    Boolean {
+        kind: BooleanKind,
+        inputs: TimedInputs,
+        output: String,
+        false_output: String,
+    },
+    Compare {
+        kind: CompareKind,
+        inputs: TimedInputs,
+        output: String,
+        false_output: String,
+    },
+    Arithmetic {
+        kind: ArithmeticKind,
+        inputs: TimedInputs,
+        clamp_min: f32,
+        clamp_max: f32,
+    },
+    Concat {
+        inputs: TimedInputs,
+        separator: String,
+        max_output_length: u32,
+    },
+    Unary(UnaryKind),
+    Not {
+        continuous_output: bool,
+        received: Option<String>,
+    },
+    Delay {
+        delay: f32,
+        reset_when_signal_received: bool,
+        reset_when_different_signal_received: bool,
+        last_received: Option<String>,
+        queue: VecDeque<(f32, String)>,
+    },
+    Memory {
+        value: String,
+        writeable: bool,
+    },
+    Oscillator {
+        wave: WaveType,
+        frequency: f32,
+        phase: f32,
+    },
+    Relay {
+        is_on: bool,
+    },
+    RegexFind {
+        regex: Option<Regex>,
+        output: String,
+        false_output: String,
+        use_capture_group: bool,
+        output_empty_capture_group: bool,
+        continuous_output: bool,
+        received: Option<String>,
+        changed: bool,
+    },
+    SignalCheck {
+        target_signal: String,
+        output: String,
+        false_output: String,
+    },
+}
+
+fn signal(pin: &str, value: impl Into<String>) -> Vec<(String, String)> {
+    vec![(pin.to_owned(), value.into())]
+}
+
+fn non_empty(pin: &str, value: &str) -> Vec<(String, String)> {
+    if value.is_empty() {
+        Vec::new()
+    } else {
+        signal(pin, value)
+    }
+}
+
+impl Logic {
+    fn from_components(c: &ItemComponents) -> Option<Self> {
+        let boolean = |kind, v: &BooleanOperatorComponent| Logic::Boolean {
+            kind,
+            inputs: TimedInputs::new(v.time_frame),
+            output: v.output.clone(),
+            false_output: v.false_output.clone(),
+        };
+        let compare = |kind, v: &EqualsComponent| Logic::Compare {
+            kind,
+            inputs: TimedInputs::new(v.time_frame),
+            output: v.output.clone(),
+            false_output: v.false_output.clone(),
+        };
+        let arithmetic = |kind, v: &ArithmeticComponent| Logic::Arithmetic {
+            kind,
+            inputs: TimedInputs::new(v.time_frame),
+            clamp_min: v.clamp_min,
+            clamp_max: v.clamp_max,
+        };
+
+        if let Some(v) = &c.and_component {
+            Some(boolean(BooleanKind::And, &v.boolean_operator))
+        } else if let Some(v) = &c.or_component {
+            Some(boolean(BooleanKind::Or, &v.boolean_operator))
+        } else if let Some(v) = &c.xor_component {
+            Some(boolean(BooleanKind::Xor, &v.boolean_operator))
+        } else if let Some(v) = &c.equals_component {
+            Some(compare(CompareKind::Equals, v))
+        } else if let Some(v) = &c.greater_component {
+            Some(compare(CompareKind::Greater, &v.equals_component))
+        } else if let Some(v) = &c.adder_component {
+            Some(arithmetic(ArithmeticKind::Add, &v.arithmetic_component))
+        } else if let Some(v) = &c.subtract_component {
+            Some(arithmetic(
+                ArithmeticKind::Subtract,
+                &v.arithmetic_component,
+            ))
+        } else if let Some(v) = &c.multiply_component {
+            Some(arithmetic(
+                ArithmeticKind::Multiply,
+                &v.arithmetic_component,
+            ))
+        } else if let Some(v) = &c.divide_component {
+            Some(arithmetic(ArithmeticKind::Divide, &v.arithmetic_component))
+        } else if let Some(v) = &c.concat_component {
+            Some(Logic::Concat {
+                inputs: TimedInputs::new(v.string_comp.time_frame.unwrap_or_default()),
+                separator: v.separator.clone(),
+                max_output_length: v.max_output_length,
+            })
+        } else if let Some(v) = &c.function_component {
+            Some(Logic::Unary(UnaryKind::Function(v.function)))
+        } else if let Some(v) = &c.trigonometric_function_component {
+            Some(Logic::Unary(UnaryKind::Trigonometric(
+                v.function,
+                v.use_radians,
+            )))
+        } else if let Some(v) = &c.modulo_component {
+            Some(Logic::Unary(UnaryKind::Modulo(v.modulus)))
+        } else if let Some(v) = &c.exponentiation_component {
+            Some(Logic::Unary(UnaryKind::Exponentiation(v.exponent)))
+        } else if let Some(v) = &c.not_component {
+            Some(Logic::Not {
+                continuous_output: v.continuous_output,
+                received: None,
+            })
+        } else if let Some(v) = &c.delay {
+            Some(Logic::Delay {
+                delay: v.delay,
+                reset_when_signal_received: v.reset_when_signal_received,
+                reset_when_different_signal_received: v.reset_when_different_signal_received,
+                last_received: None,
+                queue: VecDeque::new(),
+            })
+        } else if let Some(v) = &c.memory_component {
+            Some(Logic::Memory {
+                value: v.value.clone(),
+                writeable: v.writeable,
+            })
+        } else if let Some(v) = &c.oscillator {
+            Some(Logic::Oscillator {
+                wave: v.output_type,
+                frequency: v.frequency,
+                phase: 0.0,
+            })
+        } else if let Some(v) = &c.relay {
+            Some(Logic::Relay { is_on: v.is_on })
+        } else if let Some(v) = &c.regex_find_component {
+            Some(Logic::RegexFind {
+                regex: Regex::new(&v.expression).ok(),
+                output: v.output.clone(),
+                false_output: v.false_output.clone(),
+                use_capture_group: v.use_capture_group,
+                output_empty_capture_group: v.output_empty_capture_group,
+                continuous_output: v.continuous_output,
+                received: None,
+                changed: false,
+            })
+        } else {
+            c.signal_check.as_ref().map(|v| Logic::SignalCheck {
+                target_signal: v.target_signal.clone(),
+                output: v.output.clone(),
+                false_output: v.false_output.clone(),
+            })
+        }
+    }
+
+    /// Handles a signal arriving at `pin`, returns the signals sent immediately in response
+    fn receive(&mut self, pin: &str, value: &str) -> Vec<(String, String)> {
+        match self {
+            Logic::Boolean {
+                inputs,
+                output,
+                false_output,
+                ..
+            } => match pin {
+                "set_output" => *output = value.to_owned(),
+                "set_false_output" => *false_output = value.to_owned(),
+                //a zero signal doesn't count as received for boolean operators
+                _ if value == "0" => {}
+                _ => inputs.receive(pin, value),
+            },
+            Logic::Compare {
+                inputs,
+                output,
+                false_output,
+                ..
+            } => match pin {
+                "set_output" => *output = value.to_owned(),
+                "set_false_output" => *false_output = value.to_owned(),
+                _ => inputs.receive(pin, value),
+            },
+            Logic::Arithmetic { inputs, .. } | Logic::Concat { inputs, .. } => {
+                inputs.receive(pin, value)
+            }
+            Logic::Unary(kind) => {
+                if pin == "signal_in"
+                    && let Some(result) = value.parse().ok().and_then(|v| kind.apply(v))
+                {
+                    return signal("signal_out", result.to_string());
+                }
+            }
+            Logic::Not { received, .. } => {
+                if pin == "signal_in" {
+                    *received = Some(value.to_owned());
+                }
+            }
+            Logic::Delay {
+                delay,
+                reset_when_signal_received,
+                reset_when_different_signal_received,
+                last_received,
+                queue,
+            } => match pin {
+                "signal_in" => {
+                    if *reset_when_signal_received
+                        || (*reset_when_different_signal_received
+                            && last_received.as_deref().is_some_and(|v| v != value))
+                    {
+                        queue.clear();
+                    }
+                    *last_received = Some(value.to_owned());
+                    queue.push_back((*delay, value.to_owned()));
+                }
+                "set_delay" => {
+                    if let Ok(v) = value.parse() {
+                        *delay = v;
+                    }
+                }
+                _ => {}
+            },
+            Logic::Memory {
+                value: stored,
+                writeable,
+            } => match pin {
+                "signal_in" if *writeable => *stored = value.to_owned(),
+                "signal_store" | "lock_state" => *writeable = value == "1",
+                _ => {}
+            },
+            Logic::Oscillator {
+                wave, frequency, ..
+            } => match pin {
+                "set_frequency" => {
+                    if let Ok(v) = value.parse() {
+                        *frequency = v;
+                    }
+                }
+                "set_outputtype" => {
+                    if let Ok(v) = value.parse() {
+                        *wave = v;
+                    }
+                }
+                _ => {}
+            },
+            Logic::Relay { is_on } => match pin {
+                "toggle" => *is_on = !*is_on,
+                "set_state" => *is_on = value != "0",
+                _ => {
+                    if let Some(n) = pin.strip_prefix("signal_in")
+                        && *is_on
+                    {
+                        return signal(&format!("signal_out{}", n), value);
+                    }
+                }
+            },
+            Logic::RegexFind {
+                output,
+                received,
+                changed,
+                ..
+            } => match pin {
+                "signal_in" => {
+                    *changed = received.as_deref() != Some(value);
+                    *received = Some(value.to_owned());
+                }
+                "set_output" => *output = value.to_owned(),
+                _ => {}
+            },
+            Logic::SignalCheck {
+                target_signal,
+                output,
+                false_output,
+            } => match pin {
+                "signal_in" => {
+                    return non_empty(
+                        "signal_out",
+                        if value == target_signal {
+                            output
+                        } else {
+                            false_output
+                        },
+                    );
+                }
+                "set_output" => *output = value.to_owned(),
+                "set_targetsignal" => *target_signal = value.to_owned(),
+                _ => {}
+            },
+        }
+        Vec::new()
+    }
+
+    /// Advances the component by one tick, returns the signals it sends
+    fn update(&mut self, delta_time: f32) -> Vec<(String, String)> {
+        match self {
+            Logic::Boolean {
+                kind,
+                inputs,
+                output,
+                false_output,
+            } => {
+                let (a, b) = (inputs.is_valid(0), inputs.is_valid(1));
+                inputs.advance(delta_time);
+                let state = match kind {
+                    BooleanKind::And => a && b,
+                    BooleanKind::Or => a || b,
+                    BooleanKind::Xor => a != b,
+                };
+                non_empty("signal_out", if state { output } else { false_output })
+            }
+            Logic::Compare {
+                kind,
+                inputs,
+                output,
+                false_output,
+            } => {
+                let state = inputs.valid_values().is_some_and(|(a, b)| match kind {
+                    CompareKind::Equals => a == b,
+                    CompareKind::Greater => a
+                        .parse::<f32>()
+                        .ok()
+                        .zip(b.parse::<f32>().ok())
+                        .is_some_and(|(a, b)| a > b),
+                });
+                inputs.advance(delta_time);
+                non_empty("signal_out", if state { output } else { false_output })
+            }
+            Logic::Arithmetic {
+                kind,
+                inputs,
+                clamp_min,
+                clamp_max,
+            } => {
+                let result = inputs
+                    .valid_values()
+                    .and_then(|(a, b)| a.parse::<f32>().ok().zip(b.parse::<f32>().ok()))
+                    .and_then(|(a, b)| match kind {
+                        ArithmeticKind::Add => Some(a + b),
+                        ArithmeticKind::Subtract => Some(a - b),
+                        ArithmeticKind::Multiply => Some(a * b),
+                        ArithmeticKind::Divide => (b != 0.0).then(|| a / b),
+                    });
+                inputs.advance(delta_time);
+                match result {
+                    Some(v) => signal("signal_out", v.clamp(*clamp_min, *clamp_max).to_string()),
+                    None => Vec::new(),
+                }
+            }
+            Logic::Concat {
+                inputs,
+                separator,
+                max_output_length,
+            } => {
+                let result = inputs.valid_values().map(|(a, b)| {
+                    format!("{}{}{}", a, separator, b)
+                        .chars()
+                        .take(*max_output_length as usize)
+                        .collect::<String>()
+                });
+                inputs.advance(delta_time);
+                match result {
+                    Some(v) => signal("signal_out", v),
+                    None => Vec::new(),
+                }
+            }
+            Logic::Not {
+                continuous_output,
+                received,
+            } => match received.take() {
+                Some(v) => signal(
+                    "signal_out",
+                    if v == "0" || v.is_empty() { "1" } else { "0" },
+                ),
+                None if *continuous_output => signal("signal_out", "1"),
+                None => Vec::new(),
+            },
+            Logic::Delay { queue, .. } => {
+                let mut sent = Vec::new();
+                for (remaining, _) in queue.iter_mut() {
+                    *remaining -= delta_time;
+                }
+                while queue
+                    .front()
+                    .is_some_and(|(remaining, _)| *remaining <= 0.0)
+                {
+                    let (_, value) = queue.pop_front().unwrap();
+                    sent.push(("signal_out".to_owned(), value));
+                }
+                sent
+            }
+            Logic::Memory { value, .. } => non_empty("signal_out", value),
+            Logic::Oscillator {
+                wave,
+                frequency,
+                phase,
+            } => {
+                let previous = *phase;
+                *phase = (*phase + delta_time * *frequency).fract();
+                match wave {
+                    WaveType::Pulse => {
+                        if *phase < previous {
+                            signal("signal_out", "1")
+                        } else {
+                            Vec::new()
+                        }
+                    }
+                    WaveType::Sawtooth => signal("signal_out", (*phase * 2.0 - 1.0).to_string()),
+                    WaveType::Sine => signal(
+                        "signal_out",
+                        (*phase * std::f32::consts::TAU).sin().to_string(),
+                    ),
+                    WaveType::Square => signal("signal_out", if *phase < 0.5 { "0" } else { "1" }),
+                    WaveType::Triangle => signal(
+                        "signal_out",
+                        (if *phase < 0.5 {
+                            *phase * 4.0 - 1.0
+                        } else {
+                            3.0 - *phase * 4.0
+                        })
+                        .to_string(),
+                    ),
+                }
+            }
+            Logic::Relay { is_on } => signal("state_out", if *is_on { "1" } else { "0" }),
+            Logic::RegexFind {
+                regex,
+                output,
+                false_output,
+                use_capture_group,
+                output_empty_capture_group,
+                continuous_output,
+                received,
+                changed,
+            } => {
+                let (Some(regex), Some(received)) = (regex, received) else {
+                    return Vec::new();
+                };
+                if !*changed && !*continuous_output {
+                    return Vec::new();
+                }
+                *changed = false;
+                match regex.captures(received) {
+                    Some(captures) if *use_capture_group => {
+                        match captures.get(1).map(|v| v.as_str()) {
+                            Some(v) if !v.is_empty() || *output_empty_capture_group => {
+                                signal("signal_out", v)
+                            }
+                            _ => non_empty("signal_out", false_output),
+                        }
+                    }
+                    Some(_) => non_empty("signal_out", output),
+                    None => non_empty("signal_out", false_output),
+                }
+            }
+            Logic::Unary(_) | Logic::SignalCheck { .. } => Vec::new(),
+        }
+    }
+}
+
+impl UnaryKind {
+    fn apply(&self, v: f32) -> Option<f32> {
+        use function_component::FunctionType as F;
+        use trigonometric_function_component::FunctionType as T;
+        let result = match *self {
+            UnaryKind::Function(F::Round) => v.round(),
+            UnaryKind::Function(F::Ceil) => v.ceil(),
+            UnaryKind::Function(F::Floor) => v.floor(),
+            UnaryKind::Function(F::Factorial) => {
+                if v < 0.0 {
+                    return None;
+                }
+                (1..=v as u32).map(|i| i as f32).product()
+            }
+            UnaryKind::Function(F::AbsoluteValue) => v.abs(),
+            UnaryKind::Function(F::SquareRoot) => v.sqrt(),
+            UnaryKind::Trigonometric(function, use_radians) => {
+                let to_radians = |v: f32| if use_radians { v } else { v.to_radians() };
+                let from_radians = |v: f32| if use_radians { v } else { v.to_degrees() };
+                match function {
+                    T::Sin => to_radians(v).sin(),
+                    T::Cos => to_radians(v).cos(),
+                    T::Tan => to_radians(v).tan(),
+                    T::Asin => from_radians(v.asin()),
+                    T::Acos => from_radians(v.acos()),
+                    T::Atan => from_radians(v.atan()),
+                }
+            }
+            UnaryKind::Modulo(modulus) => v % modulus,
+            UnaryKind::Exponentiation(exponent) => v.powf(exponent),
+        };
+        result.is_finite().then_some(result)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TraceEvent {
+    pub tick: u64,
+    pub pin: Pin,
+    pub value: String,
+}
+
+/// Discrete tick simulation of the signal components of a submarine, connected through their wires
+///
+/// Signals travel through wires instantly within a tick like in game, components without a simulated logic
+/// (lights, pumps, wifi, circuit boxes...) only record the signals they receive.
+#[derive(Debug)]
+pub struct Simulator {
+    pub delta_time: f32,
+    tick: u64,
+    components: BTreeMap<u32, Logic>,
+    wires: HashMap<Pin, Vec<Pin>>,
+    injected: Vec<(Pin, String)>,
+    held: Vec<(Pin, String)>,
+    traced: Vec<Pin>,
+    trace: Vec<TraceEvent>,
+}
+
+impl Simulator {
+    pub fn new(info: &SubmarineInfo) -> Self {
+        let components = info
+            .items
+            .iter()
+            .filter_map(|item| Logic::from_components(&item.components).map(|v| (item.id, v)))
+            .collect();
+        let mut wires: HashMap<Pin, Vec<Pin>> = HashMap::new();
+        for edge in WiringGraph::new(info).edges {
+            if let WiringEdge::Wire { from, to, .. } = edge {
+                wires.entry(from.clone()).or_default().push(to.clone());
+                wires.entry(to).or_default().push(from);
+            }
+        }
+        Self::with_logic(components, wires)
+    }
+
+    fn with_logic(components: BTreeMap<u32, Logic>, wires: HashMap<Pin, Vec<Pin>>) -> Self {
+        Self {
+            delta_time: DEFAULT_DELTA_TIME,
+            tick: 0,
+            components,
+            wires,
+            injected: Vec::new(),
+            held: Vec::new(),
+            traced: Vec::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    /// Sends a signal into `pin` once at the start of the next tick
+    pub fn inject(&mut self, pin: Pin, value: impl Into<String>) {
+        self.injected.push((pin, value.into()));
+    }
+
+    /// Sends a signal into `pin` at the start of every tick until released
+    pub fn hold(&mut self, pin: Pin, value: impl Into<String>) {
+        self.release(&pin);
+        self.held.push((pin, value.into()));
+    }
+
+    pub fn release(&mut self, pin: &Pin) {
+        self.held.retain(|(v, _)| v != pin);
+    }
+
+    /// Records every signal sent from or received at `pin`
+    pub fn trace_pin(&mut self, pin: Pin) {
+        if !self.traced.contains(&pin) {
+            self.traced.push(pin);
+        }
+    }
+
+    pub fn trace(&self) -> &[TraceEvent] {
+        &self.trace
+    }
+
+    /// Last traced signal at `pin`
+    pub fn last_value(&self, pin: &Pin) -> Option<&str> {
+        self.trace
+            .iter()
+            .rev()
+            .find(|v| v.pin == *pin)
+            .map(|v| v.value.as_str())
+    }
+
+    pub fn current_tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn tick(&mut self) {
+        let mut queue = self
+            .injected
+            .drain(..)
+            .chain(self.held.iter().cloned())
+            .map(|(pin, value)| (pin, value, 0))
+            .collect::<VecDeque<_>>();
+        self.propagate(&mut queue);
+
+        let ids = self.components.keys().copied().collect::<Vec<_>>();
+        for id in ids {
+            let sent = self
+                .components
+                .get_mut(&id)
+                .unwrap()
+                .update(self.delta_time);
+            for (name, value) in sent {
+                self.send(Pin { item_id: id, name }, value, 0, &mut queue);
+            }
+            self.propagate(&mut queue);
+        }
+        self.tick += 1;
+    }
+
+    pub fn run(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            self.tick();
+        }
+    }
+
+    fn record(&mut self, pin: &Pin, value: &str) {
+        if self.traced.contains(pin) {
+            self.trace.push(TraceEvent {
+                tick: self.tick,
+                pin: pin.clone(),
+                value: value.to_owned(),
+            });
+        }
+    }
+
+    fn send(
+        &mut self,
+        from: Pin,
+        value: String,
+        steps: u32,
+        queue: &mut VecDeque<(Pin, String, u32)>,
+    ) {
+        self.record(&from, &value);
+        if let Some(targets) = self.wires.get(&from) {
+            for to in targets {
+                queue.push_back((to.clone(), value.clone(), steps));
+            }
+        }
+    }
+
+    fn propagate(&mut self, queue: &mut VecDeque<(Pin, String, u32)>) {
+        while let Some((pin, value, steps)) = queue.pop_front() {
+            self.record(&pin, &value);
+            if steps >= MAX_SIGNAL_STEPS {
+                continue;
+            }
+            let Some(logic) = self.components.get_mut(&pin.item_id) else {
+                continue;
+            };
+            for (name, value) in logic.receive(&pin.name, &value) {
+                self.send(
+                    Pin {
+                        item_id: pin.item_id,
+                        name,
+                    },
+                    value,
+                    steps + 1,
+                    queue,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pin(item_id: u32, name: &str) -> Pin {
+        Pin {
+            item_id,
+            name: name.to_owned(),
+        }
+    }
+
+    fn boolean(kind: BooleanKind) -> Logic {
+        Logic::Boolean {
+            kind,
+            inputs: TimedInputs::new(0.0),
+            output: "1".to_owned(),
+            false_output: "0".to_owned(),
+        }
+    }
+
+    fn simulator(components: Vec<(u32, Logic)>, wires: &[(Pin, Pin)]) -> Simulator {
+        let mut wire_map: HashMap<Pin, Vec<Pin>> = HashMap::new();
+        for (from, to) in wires {
+            wire_map.entry(from.clone()).or_default().push(to.clone());
+            wire_map.entry(to.clone()).or_default().push(from.clone());
+        }
+        Simulator::with_logic(components.into_iter().collect(), wire_map)
+    }
+
+    fn outputs(signals: Vec<(String, String)>) -> Vec<String> {
+        signals.into_iter().map(|(_, value)| value).collect()
+    }
+
+    #[test]
+    fn boolean_truth_tables() {
+        for (kind, expected) in [
+            (BooleanKind::And, ["0", "0", "0", "1"]),
+            (BooleanKind::Or, ["0", "1", "1", "1"]),
+            (BooleanKind::Xor, ["0", "1", "1", "0"]),
+        ] {
+            for (i, (a, b)) in [("0", "0"), ("0", "1"), ("1", "0"), ("1", "1")]
+                .into_iter()
+                .enumerate()
+            {
+                let mut logic = boolean(kind);
+                logic.receive("signal_in1", a);
+                logic.receive("signal_in2", b);
+                assert_eq!(
+                    outputs(logic.update(DEFAULT_DELTA_TIME)),
+                    [expected[i]],
+                    "{:?} of {} and {}",
+                    kind,
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn boolean_inputs_expire_after_time_frame() {
+        let mut logic = Logic::Boolean {
+            kind: BooleanKind::And,
+            inputs: TimedInputs::new(0.5),
+            output: "1".to_owned(),
+            false_output: String::new(),
+        };
+        logic.receive("signal_in1", "1");
+        logic.receive("signal_in2", "1");
+        assert_eq!(outputs(logic.update(0.3)), ["1"]);
+        assert_eq!(outputs(logic.update(0.3)), ["1"]);
+        //an empty false output sends nothing
+        assert!(logic.update(0.3).is_empty());
+    }
+
+    #[test]
+    fn delay_sends_after_delay() {
+        let mut sim = simulator(
+            vec![(
+                1,
+                Logic::Delay {
+                    delay: 0.5,
+                    reset_when_signal_received: false,
+                    reset_when_different_signal_received: false,
+                    last_received: None,
+                    queue: VecDeque::new(),
+                },
+            )],
+            &[],
+        );
+        sim.delta_time = 0.25;
+        sim.trace_pin(pin(1, "signal_out"));
+        sim.inject(pin(1, "signal_in"), "5");
+        sim.run(4);
+        let trace = sim
+            .trace()
+            .iter()
+            .map(|v| (v.tick, v.value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(trace, [(1, "5")]);
+    }
+
+    #[test]
+    fn delay_resets_when_different_signal_received() {
+        let mut logic = Logic::Delay {
+            delay: 0.5,
+            reset_when_signal_received: false,
+            reset_when_different_signal_received: true,
+            last_received: None,
+            queue: VecDeque::new(),
+        };
+        logic.receive("signal_in", "1");
+        assert!(logic.update(0.25).is_empty());
+        logic.receive("signal_in", "2");
+        assert!(logic.update(0.25).is_empty());
+        assert_eq!(outputs(logic.update(0.25)), ["2"]);
+    }
+
+    #[test]
+    fn memory_latches_only_while_writeable() {
+        let mut logic = Logic::Memory {
+            value: "0".to_owned(),
+            writeable: false,
+        };
+        logic.receive("signal_in", "a");
+        assert_eq!(outputs(logic.update(DEFAULT_DELTA_TIME)), ["0"]);
+        logic.receive("signal_store", "1");
+        logic.receive("signal_in", "a");
+        logic.receive("signal_store", "0");
+        logic.receive("signal_in", "b");
+        assert_eq!(outputs(logic.update(DEFAULT_DELTA_TIME)), ["a"]);
+    }
+
+    #[test]
+    fn relay_passes_signals_through_while_on() {
+        let mut logic = Logic::Relay { is_on: true };
+        assert_eq!(
+            logic.receive("signal_in2", "x"),
+            [("signal_out2".to_owned(), "x".to_owned())]
+        );
+        logic.receive("toggle", "1");
+        assert!(logic.receive("signal_in1", "x").is_empty());
+        assert_eq!(outputs(logic.update(DEFAULT_DELTA_TIME)), ["0"]);
+        logic.receive("set_state", "1");
+        assert_eq!(outputs(logic.update(DEFAULT_DELTA_TIME)), ["1"]);
+    }
+
+    /// Temperature above the threshold turns off the relay feeding the fission rate to the reactor
+    #[test]
+    fn reactor_controller_trace() {
+        let mut sim = simulator(
+            vec![
+                (
+                    1,
+                    Logic::Compare {
+                        kind: CompareKind::Greater,
+                        inputs: TimedInputs::new(0.0),
+                        output: "1".to_owned(),
+                        false_output: "0".to_owned(),
+                    },
+                ),
+                (
+                    2,
+                    Logic::Not {
+                        continuous_output: false,
+                        received: None,
+                    },
+                ),
+                (3, Logic::Relay { is_on: true }),
+            ],
+            &[
+                (pin(1, "signal_out"), pin(2, "signal_in")),
+                (pin(2, "signal_out"), pin(3, "set_state")),
+            ],
+        );
+        let fission_rate = pin(3, "signal_out1");
+        sim.trace_pin(fission_rate.clone());
+        sim.trace_pin(pin(3, "state_out"));
+        sim.hold(pin(1, "signal_in2"), "50");
+        sim.hold(pin(3, "signal_in1"), "75");
+
+        sim.hold(pin(1, "signal_in1"), "40");
+        sim.run(2);
+        assert_eq!(sim.last_value(&fission_rate), Some("75"));
+        assert_eq!(sim.last_value(&pin(3, "state_out")), Some("1"));
+
+        sim.hold(pin(1, "signal_in1"), "60");
+        sim.run(2);
+        assert_eq!(sim.last_value(&pin(3, "state_out")), Some("0"));
+        //the relay is switched off during the first hot tick, so the held signal only gets through before that
+        let last_sent = sim
+            .trace()
+            .iter()
+            .filter(|v| v.pin == fission_rate)
+            .map(|v| v.tick)
+            .max();
+        assert_eq!(last_sent, Some(2));
+        assert_eq!(sim.current_tick(), 4);
+
+        sim.hold(pin(1, "signal_in1"), "40");
+        sim.run(2);
+        assert_eq!(sim.last_value(&pin(3, "state_out")), Some("1"));
+        assert!(
+            sim.trace()
+                .iter()
+                .any(|v| v.pin == fission_rate && v.tick == 5)
+        );
+    }
+}
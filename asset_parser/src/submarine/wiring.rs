@@ -0,0 +1,209 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Write,
+};
+
+use serde::Serialize;
+
+use crate::shared::{identifier::Identifier, submarine_info::SubmarineInfo};
+
+/// A connection pin of an item's connection panel
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Pin {
+    pub item_id: u32,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WiringNode {
+    pub id: u32,
+    pub identifier: Identifier,
+    pub pins: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WiringEdge {
+    /// A wire item connecting two pins
+    Wire {
+        wire_id: u32,
+        wire_identifier: Option<Identifier>,
+        from: Pin,
+        to: Pin,
+    },
+    /// Items linked to each other through `linked_to_ids`
+    Link { from: u32, to: u32 },
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct WiringGraph {
+    pub nodes: Vec<WiringNode>,
+    pub edges: Vec<WiringEdge>,
+    /// Wires that are only connected on one end
+    pub dangling_wires: Vec<(u32, Pin)>,
+}
+
+impl WiringGraph {
+    pub fn new(info: &SubmarineInfo) -> Self {
+        let mut graph = Self::default();
+
+        let mut wire_ends: BTreeMap<u32, Vec<Pin>> = BTreeMap::new();
+        for item in &info.items {
+            let Some(panel) = &item.components.connection_panel else {
+                continue;
+            };
+            for connection in &panel.connections {
+                for (wire_id, _) in &connection.loaded_wires {
+                    wire_ends.entry(*wire_id as u32).or_default().push(Pin {
+                        item_id: item.id,
+                        name: connection.name.clone(),
+                    });
+                }
+            }
+            graph.nodes.push(WiringNode {
+                id: item.id,
+                identifier: item.identifier.clone(),
+                pins: panel.connections.iter().map(|v| v.name.clone()).collect(),
+            });
+        }
+
+        for (wire_id, mut ends) in wire_ends {
+            let wire_identifier = info
+                .items
+                .iter()
+                .find(|v| v.id == wire_id)
+                .map(|v| v.identifier.clone());
+            if ends.len() == 1 {
+                graph.dangling_wires.push((wire_id, ends.remove(0)));
+                continue;
+            }
+            let from = ends.remove(0);
+            for to in ends {
+                graph.edges.push(WiringEdge::Wire {
+                    wire_id,
+                    wire_identifier: wire_identifier.clone(),
+                    from: from.clone(),
+                    to,
+                });
+            }
+        }
+
+        let wire_ids = info
+            .items
+            .iter()
+            .filter(|v| v.components.wire.is_some())
+            .map(|v| v.id)
+            .collect::<HashSet<_>>();
+        let item_ids = info
+            .items
+            .iter()
+            .filter(|v| !wire_ids.contains(&v.id))
+            .map(|v| v.id)
+            .collect::<HashSet<_>>();
+        let mut links = HashSet::new();
+        for item in info.items.iter().filter(|v| item_ids.contains(&v.id)) {
+            for linked_id in item.linked_to_ids.iter().filter(|v| item_ids.contains(v)) {
+                //links are usually stored on both ends
+                if links.insert((item.id.min(*linked_id), item.id.max(*linked_id))) {
+                    graph.edges.push(WiringEdge::Link {
+                        from: item.id,
+                        to: *linked_id,
+                    });
+                }
+            }
+        }
+        let linked_items = info
+            .items
+            .iter()
+            .filter(|v| {
+                links.iter().any(|(a, b)| *a == v.id || *b == v.id)
+                    && !graph.nodes.iter().any(|node| node.id == v.id)
+            })
+            .map(|v| WiringNode {
+                id: v.id,
+                identifier: v.identifier.clone(),
+                pins: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        graph.nodes.extend(linked_items);
+
+        graph
+    }
+
+    fn port(&self, pin: &Pin) -> String {
+        let index = self
+            .nodes
+            .iter()
+            .find(|v| v.id == pin.item_id)
+            .and_then(|v| v.pins.iter().position(|p| *p == pin.name));
+        match index {
+            Some(index) => format!("n{}:p{}", pin.item_id, index),
+            None => format!("n{}", pin.item_id),
+        }
+    }
+
+    /// Graphviz representation, nodes are records with one port per pin
+    pub fn to_dot(&self) -> String {
+        let mut s = String::from("graph wiring {\n    node [shape=record];\n");
+        for node in &self.nodes {
+            let pins = node
+                .pins
+                .iter()
+                .enumerate()
+                .map(|(i, pin)| format!("<p{}> {}", i, escape_record(pin)))
+                .collect::<Vec<_>>()
+                .join("|");
+            let _ = writeln!(
+                s,
+                "    n{} [label=\"{{{} #{}|{{{}}}}}\"];",
+                node.id,
+                escape_record(node.identifier.as_str()),
+                node.id,
+                pins
+            );
+        }
+        for edge in &self.edges {
+            let _ = match edge {
+                WiringEdge::Wire {
+                    wire_id,
+                    wire_identifier,
+                    from,
+                    to,
+                } => writeln!(
+                    s,
+                    "    {} -- {} [label=\"{} #{}\"];",
+                    self.port(from),
+                    self.port(to),
+                    wire_identifier.as_ref().map_or("wire", Identifier::as_str),
+                    wire_id
+                ),
+                WiringEdge::Link { from, to } => {
+                    writeln!(s, "    n{} -- n{} [style=dashed];", from, to)
+                }
+            };
+        }
+        for (wire_id, pin) in &self.dangling_wires {
+            let _ = writeln!(
+                s,
+                "    dangling{} [shape=point];\n    {} -- dangling{} [label=\"#{}\", color=red];",
+                wire_id,
+                self.port(pin),
+                wire_id,
+                wire_id
+            );
+        }
+        s.push_str("}\n");
+        s
+    }
+}
+
+fn escape_record(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}